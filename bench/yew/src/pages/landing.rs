@@ -1,5 +1,5 @@
 use image_rs::yew::Image;
-use image_rs::{Decoding, Layout, ObjectFit, Position};
+use image_rs::{Decoding, Layout, ObjectFit, Placeholder, Position};
 use yew::prelude::*;
 
 #[function_component(LandingPage)]
@@ -14,7 +14,7 @@ pub fn landing_page() -> Html {
                     height="600"
                     layout={Layout::Responsive}
                     quality="high"
-                    placeholder="https://placehold.co/800?text=Hello+World&font=roboto"
+                    placeholder={Placeholder::Image("https://placehold.co/800?text=Hello+World&font=roboto".into())}
                     fallback_src="https://placehold.co/800?text=Hello+World&font=roboto"
                     priority={i < 5}
                     decoding={Decoding::Async}
@@ -1,7 +1,7 @@
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 use image_rs::dioxus::Image;
-use image_rs::{AriaLive, AriaPressed, Decoding, Layout, Loading, ObjectFit, Position};
+use image_rs::{AriaLive, AriaPressed, Decoding, Layout, Loading, ObjectFit, Placeholder, Position};
 
 fn main() {
     dioxus_logger::init(tracing::Level::INFO).expect("failed to init logger");
@@ -56,8 +56,7 @@ pub fn Example4() -> Element {
         layout: Layout::Responsive,
         width: "600",
         height: "400",
-        placeholder: "blur",
-        blur_data_url: "https://placehold.co/10x10",
+        placeholder: Placeholder::Blur("https://placehold.co/10x10".into()),
     })
 }
 
@@ -79,12 +78,21 @@ pub fn Example6() -> Element {
         tracing::error!("Failed to load image");
     };
 
-    rsx!(Image {
-        src: "https://invalid.url",
-        fallback_src: "https://jsonplaceholder.typicode.com/posts/1",
-        alt: "Broken Image",
-        on_error: on_error,
-    })
+    rsx! {
+        // Recovers with the fallback image.
+        Image {
+            src: "https://invalid.url",
+            fallback_src: "https://placehold.co/300x200/png?text=Fallback",
+            alt: "Fallback Image",
+            on_error: on_error,
+        }
+        // Deliberately broken, without a fallback.
+        Image {
+            src: "https://invalid.url",
+            alt: "Broken Image",
+            on_error: on_error,
+        }
+    }
 }
 
 #[component]
@@ -251,8 +259,7 @@ pub fn LandingPage() -> Element {
     layout: Layout::Responsive,
     width: "600",
     height: "400",
-    placeholder: "blur",
-    blur_data_url: "https://placehold.co/10x10"
+    placeholder: Placeholder::Blur("https://placehold.co/10x10".into())
 }}"##
                     }
                     Example4 {}
@@ -277,12 +284,19 @@ pub fn LandingPage() -> Element {
 
                 div {
                     class: "flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md",
-                    h2 { class: "text-xl font-bold mb-2", "Error Handling (Press F12)" }
+                    h2 { class: "text-xl font-bold mb-2", "Fallback & Error Handling (Press F12)" }
                     pre {
                         class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
-                        r##"Image {{
+                        r##"// Recovers with the fallback image.
+Image {{
+    src: "https://invalid.url",
+    fallback_src: "https://placehold.co/300x200/png?text=Fallback",
+    alt: "Fallback Image",
+    on_error: |_| tracing::error!("Failed to load image")
+}}
+// Deliberately broken, without a fallback.
+Image {{
     src: "https://invalid.url",
-    fallback_src: "https://jsonplaceholder.typicode.com/posts/1",
     alt: "Broken Image",
     on_error: |_| tracing::error!("Failed to load image")
 }}"##
@@ -1,5 +1,5 @@
 use image_rs::leptos::Image;
-use image_rs::{Decoding, Layout, ObjectFit, Position, Loading};
+use image_rs::{Decoding, Dimension, Layout, ObjectFit, Placeholder, Position, Loading};
 use leptos::{*, prelude::*};
 use leptos::logging::log;
 
@@ -15,8 +15,8 @@ pub fn Example2() -> impl IntoView {
             src="https://placehold.co/300x200"
             alt="Fixed Layout"
             layout={Layout::Fixed}
-            width="300"
-            height="200"
+            width=Dimension::Px(300.0)
+            height=Dimension::Px(200.0)
         />
     }
 }
@@ -28,8 +28,8 @@ pub fn Example3() -> impl IntoView {
             src="https://placehold.co/600x400"
             alt="Responsive Layout"
             layout={Layout::Responsive}
-            width="600"
-            height="400"
+            width=Dimension::Px(600.0)
+            height=Dimension::Px(400.0)
         />
     }
 }
@@ -41,10 +41,9 @@ pub fn Example4() -> impl IntoView {
             src="https://placehold.co/600x400"
             alt="Blurred Image"
             layout={Layout::Responsive}
-            width="600"
-            height="400"
-            placeholder="blur"
-            blur_data_url="https://placehold.co/10x10"
+            width=Dimension::Px(600.0)
+            height=Dimension::Px(400.0)
+            placeholder=Placeholder::Blur("https://placehold.co/10x10".into())
         />
     }
 }
@@ -56,8 +55,8 @@ pub fn Example5() -> impl IntoView {
             src="https://placehold.co/600x400"
             alt="Cover Fit"
             layout={Layout::Responsive}
-            width="600"
-            height="400"
+            width=Dimension::Px(600.0)
+            height=Dimension::Px(400.0)
             object_fit={ObjectFit::Cover}
         />
     }
@@ -66,9 +65,16 @@ pub fn Example5() -> impl IntoView {
 #[component]
 pub fn Example6() -> impl IntoView {
     view! {
+        // Recovers with the fallback image.
+        <Image
+            src="https://invalid.url"
+            fallback_src="https://placehold.co/300x200/png?text=Fallback"
+            alt="Fallback Image"
+            on_error={Callback::new(|e| log!("Failed to load: {}", e))}
+        />
+        // Deliberately broken, without a fallback.
         <Image
             src="https://invalid.url"
-            fallback_src="https://jsonplaceholder.typicode.com/posts/1"
             alt="Broken Image"
             on_error={Callback::new(|e| log!("Failed to load: {}", e))}
         />
@@ -142,8 +148,8 @@ pub fn Example13() -> impl IntoView {
             src="https://placehold.co/500x300"
             alt="Lazy Boundary Example"
             layout={Layout::Responsive}
-            width="500"
-            height="300"
+            width=Dimension::Px(500.0)
+            height=Dimension::Px(300.0)
             // lazy_boundary="500px"
         />
     }
@@ -169,8 +175,8 @@ pub fn Example15() -> impl IntoView {
             object_fit={ObjectFit::Cover}
             object_position={Position::TopRight}
             layout={Layout::Responsive}
-            width="600"
-            height="400"
+            width=Dimension::Px(600.0)
+            height=Dimension::Px(400.0)
         />
     }
 }
@@ -207,6 +213,7 @@ pub fn Example1() -> impl IntoView {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"use image_rs::leptos::Image;
+use image_rs::Dimension;
 use image_rs::Layout;
 use leptos::{*, prelude::*};
 
@@ -217,8 +224,8 @@ pub fn Example2() -> impl IntoView {
             src="https://placehold.co/300x200"
             alt="Fixed Layout"
             layout={Layout::Fixed}
-            width="300"
-            height="200"
+            width=Dimension::Px(300.0)
+            height=Dimension::Px(200.0)
         />
     }
 }"# }
@@ -231,6 +238,7 @@ pub fn Example2() -> impl IntoView {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"use image_rs::leptos::Image;
+use image_rs::Dimension;
 use image_rs::Layout;
 use leptos::{*, prelude::*};
 
@@ -241,8 +249,8 @@ pub fn Example3() -> impl IntoView {
             src="https://placehold.co/600x400"
             alt="Responsive Layout"
             layout={Layout::Responsive}
-            width="600"
-            height="400"
+            width=Dimension::Px(600.0)
+            height=Dimension::Px(400.0)
         />
     }
 }"# }
@@ -255,7 +263,8 @@ pub fn Example3() -> impl IntoView {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"use image_rs::leptos::Image;
-use image_rs::Layout;
+use image_rs::Dimension;
+use image_rs::{Layout, Placeholder};
 use leptos::{*, prelude::*};
 
 #[component]
@@ -265,10 +274,9 @@ pub fn Example4() -> impl IntoView {
             src="https://placehold.co/600x400"
             alt="Blurred Image"
             layout={Layout::Responsive}
-            width="600"
-            height="400"
-            placeholder="blur"
-            blur_data_url="https://placehold.co/10x10"
+            width=Dimension::Px(600.0)
+            height=Dimension::Px(400.0)
+            placeholder=Placeholder::Blur("https://placehold.co/10x10".into())
         />
     }
 }"# }
@@ -281,6 +289,7 @@ pub fn Example4() -> impl IntoView {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"use image_rs::leptos::Image;
+use image_rs::Dimension;
 use image_rs::{Layout, ObjectFit};
 use leptos::{*, prelude::*};
 
@@ -291,8 +300,8 @@ pub fn Example5() -> impl IntoView {
             src="https://placehold.co/600x400"
             alt="Cover Fit"
             layout={Layout::Responsive}
-            width="600"
-            height="400"
+            width=Dimension::Px(600.0)
+            height=Dimension::Px(400.0)
             object_fit={ObjectFit::Cover}
         />
     }
@@ -301,7 +310,7 @@ pub fn Example5() -> impl IntoView {
                     <Example5 />
                 </div>
                 <div class="flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md">
-                    <h2 class="text-xl font-bold mb-2">{ "Error Handling (Press F12)" }</h2>
+                    <h2 class="text-xl font-bold mb-2">{ "Fallback & Error Handling (Press F12)" }</h2>
                     <pre
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
@@ -312,9 +321,16 @@ use leptos::{*, prelude::*};
 #[component]
 pub fn Example6() -> impl IntoView {
     view! {
+        // Recovers with the fallback image.
+        <Image
+            src="https://invalid.url"
+            fallback_src="https://placehold.co/300x200/png?text=Fallback"
+            alt="Fallback Image"
+            on_error={Callback::new(|e| log!("Failed to load: {}", e))}
+        />
+        // Deliberately broken, without a fallback.
         <Image
             src="https://invalid.url"
-            fallback_src="https://jsonplaceholder.typicode.com/posts/1"
             alt="Broken Image"
             on_error={Callback::new(|e| log!("Failed to load: {}", e))}
         />
@@ -470,6 +486,7 @@ pub fn Example12() -> impl IntoView {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"use image_rs::leptos::Image;
+use image_rs::Dimension;
 use image_rs::Layout;
 use leptos::{*, prelude::*};
             
@@ -480,8 +497,8 @@ pub fn Example13() -> impl IntoView {
             src="https://placehold.co/500x300"
             alt="Lazy Boundary Example"
             layout={Layout::Responsive}
-            width="500"
-            height="300"
+            width=Dimension::Px(500.0)
+            height=Dimension::Px(300.0)
             lazy_boundary="500px"
         />
     }
@@ -516,6 +533,7 @@ pub fn Example14() -> impl IntoView {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"use image_rs::leptos::Image;
+use image_rs::Dimension;
 use image_rs::{Layout, Position, ObjectFit};
 use leptos::{*, prelude::*};
             
@@ -528,8 +546,8 @@ pub fn Example15() -> impl IntoView {
             object_fit={ObjectFit::Cover}
             object_position={Position::TopRight}
             layout={Layout::Responsive}
-            width="600"
-            height="400"
+            width=Dimension::Px(600.0)
+            height=Dimension::Px(400.0)
         />
     }
 }"# }
@@ -1,5 +1,5 @@
 use image_rs::yew::Image;
-use image_rs::{Decoding, Layout, ObjectFit, Position, Loading, AriaLive, AriaPressed};
+use image_rs::{Decoding, Layout, ObjectFit, Placeholder, Position, Loading, AriaLive, AriaPressed};
 use yew::prelude::*;
 
 #[function_component(Example1)]
@@ -42,8 +42,7 @@ pub fn example4() -> Html {
             layout={Layout::Responsive}
             width="600"
             height="400"
-            placeholder="blur"
-            blur_data_url="https://placehold.co/10x10"
+            placeholder={Placeholder::Blur("https://placehold.co/10x10".into())}
         />
     }
 }
@@ -65,12 +64,21 @@ pub fn example5() -> Html {
 #[function_component(Example6)]
 pub fn example6() -> Html {
     html! {
-        <Image
-            src="https://invalid.url"
-            fallback_src="https://jsonplaceholder.typicode.com/posts/1"
-            alt="Broken Image"
-            on_error={Callback::from(|e| log::error!("Failed to load: {}", e))}
-        />
+        <>
+            // Recovers with the fallback image.
+            <Image
+                src="https://invalid.url"
+                fallback_src="https://placehold.co/300x200/png?text=Fallback"
+                alt="Fallback Image"
+                on_error={Callback::from(|e| log::error!("Failed to load: {}", e))}
+            />
+            // Deliberately broken, without a fallback.
+            <Image
+                src="https://invalid.url"
+                alt="Broken Image"
+                on_error={Callback::from(|e| log::error!("Failed to load: {}", e))}
+            />
+        </>
     }
 }
 
@@ -245,7 +253,7 @@ pub fn example3() -> Html {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"use image_rs::yew::Image;
-use image_rs::Layout;
+use image_rs::{Layout, Placeholder};
 use yew::prelude::*;
 
 #[function_component(Example4)]
@@ -257,8 +265,7 @@ pub fn example4() -> Html {
             layout={Layout::Responsive}
             width="600"
             height="400"
-            placeholder="blur"
-            blur_data_url="https://placehold.co/10x10"
+            placeholder={Placeholder::Blur("https://placehold.co/10x10".into())}
         />
     }
 }"# }
@@ -291,7 +298,7 @@ pub fn example5() -> Html {
                     <Example5 />
                 </div>
                 <div class="flex flex-col items-center bg-gray-200 p-4 rounded-lg shadow-md">
-                    <h2 class="text-xl font-bold mb-2">{ "Error Handling (Press F12)" }</h2>
+                    <h2 class="text-xl font-bold mb-2">{ "Fallback & Error Handling (Press F12)" }</h2>
                     <pre
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
@@ -303,12 +310,21 @@ use yew::Callback;
 #[function_component(Example6)]
 pub fn example6() -> Html {
     html! {
-        <Image
-            src="https://invalid.url"
-            fallback_src="https://jsonplaceholder.typicode.com/posts/1"
-            alt="Broken Image"
-            on_error={Callback::from(|e| log::error!("Failed to load: {}", e))}
-        />
+        <>
+            // Recovers with the fallback image.
+            <Image
+                src="https://invalid.url"
+                fallback_src="https://placehold.co/300x200/png?text=Fallback"
+                alt="Fallback Image"
+                on_error={Callback::from(|e| log::error!("Failed to load: {}", e))}
+            />
+            // Deliberately broken, without a fallback.
+            <Image
+                src="https://invalid.url"
+                alt="Broken Image"
+                on_error={Callback::from(|e| log::error!("Failed to load: {}", e))}
+            />
+        </>
     }
 }"# }
                     </pre>
@@ -0,0 +1,109 @@
+//! Compile-time macros for [image-rs](https://docs.rs/image-rs).
+//!
+//! [`static_image!`] reads an image bundled with the app when compiling, like the static image
+//! imports of Next.js, so its size and blur placeholder never have to be written by hand.
+
+use image_rs::build::{LqipOptions, lqip_with};
+use proc_macro::TokenStream;
+use quote::quote;
+use std::path::{Path, PathBuf};
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitStr, Token, parse_macro_input};
+
+/// Arguments of [`static_image!`]: a path, then an optional `src = "..."`.
+struct StaticImageInput {
+    path: LitStr,
+    src: Option<LitStr>,
+}
+
+impl Parse for StaticImageInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let mut src = None;
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "src" if src.is_none() => src = Some(input.parse()?),
+                "src" => return Err(syn::Error::new(key.span(), "duplicate `src`")),
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("unknown argument `{key}`, expected `src`"),
+                    ));
+                }
+            }
+        }
+        if !input.is_empty() {
+            return Err(input.error("expected `,`"));
+        }
+        Ok(StaticImageInput { path, src })
+    }
+}
+
+/// Embeds the size and blur placeholder of an image file into an `image_rs::StaticImage`.
+///
+/// The path is relative to the `Cargo.toml` of the crate being compiled. The public URL of the
+/// image defaults to the same path from the root of the site, e.g. `/assets/hero.jpg`, and can
+/// be set with `src = "..."` when the assets are served from elsewhere. The crate is rebuilt
+/// whenever the image changes.
+///
+/// The result is a constant expression, passed to the `static_image` prop of the Yew, Dioxus
+/// or Leptos `Image`:
+///
+/// ```rust,ignore
+/// use image_rs::StaticImage;
+/// use image_rs::yew::Image;
+/// use image_rs_macros::static_image;
+///
+/// const HERO: StaticImage = static_image!("assets/hero.jpg");
+/// const LOGO: StaticImage = static_image!("assets/logo.png", src = "/static/logo.png");
+///
+/// html! { <Image static_image={HERO} alt="Hero" layout={Layout::Responsive} /> }
+/// ```
+///
+/// A missing or undecodable file is a compile error pointing at the path.
+#[proc_macro]
+pub fn static_image(input: TokenStream) -> TokenStream {
+    let StaticImageInput { path, src } = parse_macro_input!(input as StaticImageInput);
+    expand(&path, src.as_ref())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(path: &LitStr, src: Option<&LitStr>) -> syn::Result<proc_macro2::TokenStream> {
+    let relative = path.value();
+    let file = manifest_dir().join(&relative);
+    let lqip = lqip_with(&file, &LqipOptions::default())
+        .map_err(|error| syn::Error::new(path.span(), error))?;
+
+    let file = file.to_string_lossy().into_owned();
+    let src = src.map_or_else(|| public_url(&relative), LitStr::value);
+    let (width, height, blur_data_url) = (lqip.width, lqip.height, lqip.data_url);
+    Ok(quote! {
+        {
+            // Tracks the file, so the constant is regenerated when the image changes.
+            const _: &[u8] = include_bytes!(#file);
+            ::image_rs::StaticImage::new(#src, #width, #height).with_blur_data_url(#blur_data_url)
+        }
+    })
+}
+
+fn manifest_dir() -> PathBuf {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+}
+
+/// Returns the URL of a file served from the root of the site, with `/` separators.
+fn public_url(relative: &str) -> String {
+    let path = Path::new(relative);
+    let segments: Vec<_> = path
+        .components()
+        .filter_map(|component| match component {
+            std::path::Component::Normal(segment) => Some(segment.to_string_lossy()),
+            _ => None,
+        })
+        .collect();
+    format!("/{}", segments.join("/"))
+}
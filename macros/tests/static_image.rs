@@ -0,0 +1,19 @@
+use image_rs::{Placeholder, StaticImage};
+use image_rs_macros::static_image;
+
+const BANNER: StaticImage = static_image!("tests/fixtures/banner.png");
+
+#[test]
+fn embeds_the_size_and_placeholder() {
+    assert_eq!(BANNER.src, "/tests/fixtures/banner.png");
+    assert_eq!((BANNER.width, BANNER.height), (48, 32));
+    assert!(BANNER.blur_data_url.starts_with("data:image/webp;base64,"));
+    assert!(matches!(BANNER.placeholder::<()>(), Placeholder::Blur(_)));
+}
+
+#[test]
+fn overrides_the_public_url() {
+    let banner = static_image!("./tests/fixtures/banner.png", src = "/static/banner.png",);
+    assert_eq!(banner.src, "/static/banner.png");
+    assert_eq!(banner.blur_data_url, BANNER.blur_data_url);
+}
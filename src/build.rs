@@ -0,0 +1,405 @@
+//! Build-time generation of low-quality image placeholders (LQIP).
+//!
+//! Turns an image file into a tiny blurred data URL for [`Placeholder::Blur`], along with its
+//! intrinsic size and dominant color, so placeholders no longer have to be produced by hand.
+//! This module is native only and meant to be called from a `build.rs`:
+//!
+//! ```rust,no_run
+//! // build.rs
+//! use image_rs::build::lqip;
+//! use std::{env, fs, path::Path};
+//!
+//! fn main() {
+//!     println!("cargo:rerun-if-changed=assets/hero.jpg");
+//!     let hero = lqip("assets/hero.jpg").expect("failed to generate the placeholder");
+//!     let out = Path::new(&env::var("OUT_DIR").unwrap()).join("placeholders.rs");
+//!     fs::write(out, hero.to_consts("HERO")).unwrap();
+//! }
+//! ```
+//!
+//! The constants are then included next to the component:
+//!
+//! ```rust,ignore
+//! include!(concat!(env!("OUT_DIR"), "/placeholders.rs"));
+//!
+//! let placeholder = Placeholder::Blur(HERO_BLUR_DATA_URL.into());
+//! let (width, height): (Dimension, Dimension) = (HERO_WIDTH.into(), HERO_HEIGHT.into());
+//! ```
+//!
+//! For images bundled with the crate, the `static_image!` macro of `image-rs-macros` builds the
+//! whole [`StaticImage`] at compile time instead.
+//!
+//! [`Placeholder::Blur`]: crate::Placeholder::Blur
+//! [`StaticImage`]: crate::StaticImage
+
+use crate::Placeholder;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ExtendedColorType, ImageError};
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Encoding of the generated placeholder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LqipFormat {
+    /// Lossless WebP, keeping transparency (default).
+    #[default]
+    WebP,
+
+    /// JPEG of the given quality, from 1 to 100, painted over white when transparent.
+    Jpeg(u8),
+}
+
+impl LqipFormat {
+    /// Returns the MIME type of the format.
+    pub fn mime(&self) -> &'static str {
+        match self {
+            LqipFormat::WebP => "image/webp",
+            LqipFormat::Jpeg(_) => "image/jpeg",
+        }
+    }
+}
+
+/// Options of [`lqip_with`].
+///
+/// # Example
+/// ```rust
+/// use image_rs::build::{LqipFormat, LqipOptions};
+///
+/// let options = LqipOptions::default()
+///     .with_size(24)
+///     .with_format(LqipFormat::Jpeg(60));
+/// assert_eq!(options.size, 24);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LqipOptions {
+    /// Largest side of the placeholder, in pixels. The aspect ratio is kept.
+    pub size: u32,
+
+    /// Standard deviation of the gaussian blur, in pixels of the placeholder.
+    pub sigma: f32,
+
+    /// Encoding of the placeholder.
+    pub format: LqipFormat,
+}
+
+impl Default for LqipOptions {
+    fn default() -> Self {
+        LqipOptions {
+            size: 16,
+            sigma: 1.0,
+            format: LqipFormat::default(),
+        }
+    }
+}
+
+impl LqipOptions {
+    /// Sets the largest side of the placeholder, at least one pixel.
+    pub fn with_size(mut self, size: u32) -> Self {
+        self.size = size.max(1);
+        self
+    }
+
+    /// Sets the standard deviation of the blur; `0.0` disables it.
+    pub fn with_sigma(mut self, sigma: f32) -> Self {
+        self.sigma = sigma.max(0.0);
+        self
+    }
+
+    /// Sets the encoding of the placeholder.
+    pub fn with_format(mut self, format: LqipFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// A placeholder generated from an image, with the intrinsic properties of that image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lqip {
+    /// The tiny blurred image, as a `data:` URL.
+    pub data_url: String,
+
+    /// Intrinsic width of the source image, in pixels.
+    pub width: u32,
+
+    /// Intrinsic height of the source image, in pixels.
+    pub height: u32,
+
+    /// The most common color of the image, as RGB.
+    pub dominant_color: [u8; 3],
+}
+
+impl Lqip {
+    /// Returns the dominant color as a CSS hex color, e.g. `#1e40af`.
+    pub fn color_css(&self) -> String {
+        let [r, g, b] = self.dominant_color;
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+
+    /// Returns a [`Placeholder::Blur`] of the data URL.
+    pub fn blur<V>(&self) -> Placeholder<V> {
+        Placeholder::Blur(self.data_url.clone())
+    }
+
+    /// Returns a [`Placeholder::Color`] of the dominant color.
+    pub fn color<V>(&self) -> Placeholder<V> {
+        Placeholder::Color(self.color_css())
+    }
+
+    /// Returns Rust source declaring `{NAME}_BLUR_DATA_URL`, `{NAME}_WIDTH`, `{NAME}_HEIGHT`
+    /// and `{NAME}_COLOR` constants, to be written to `OUT_DIR` and `include!`d.
+    pub fn to_consts(&self, name: &str) -> String {
+        format!(
+            "pub const {name}_BLUR_DATA_URL: &str = {:?};\n\
+             pub const {name}_WIDTH: u32 = {};\n\
+             pub const {name}_HEIGHT: u32 = {};\n\
+             pub const {name}_COLOR: &str = {:?};\n",
+            self.data_url,
+            self.width,
+            self.height,
+            self.color_css()
+        )
+    }
+}
+
+/// Error returned when a placeholder cannot be generated.
+#[derive(Debug)]
+pub enum LqipError {
+    /// The file could not be read.
+    Io {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying error.
+        source: io::Error,
+    },
+
+    /// The bytes are not an image of a supported format (JPEG, PNG or WebP).
+    Decode(ImageError),
+
+    /// The placeholder could not be encoded.
+    Encode(ImageError),
+}
+
+impl fmt::Display for LqipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LqipError::Io { path, source } => {
+                write!(f, "failed to read {}: {source}", path.display())
+            }
+            LqipError::Decode(error) => write!(f, "failed to decode the image: {error}"),
+            LqipError::Encode(error) => write!(f, "failed to encode the placeholder: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for LqipError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LqipError::Io { source, .. } => Some(source),
+            LqipError::Decode(error) | LqipError::Encode(error) => Some(error),
+        }
+    }
+}
+
+/// Generates the placeholder of the image at `path` with the default [`LqipOptions`]: a 16px
+/// lossless WebP blurred by one pixel.
+pub fn lqip(path: impl AsRef<Path>) -> Result<Lqip, LqipError> {
+    lqip_with(path, &LqipOptions::default())
+}
+
+/// Generates the placeholder of the image at `path`.
+pub fn lqip_with(path: impl AsRef<Path>, options: &LqipOptions) -> Result<Lqip, LqipError> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path).map_err(|source| LqipError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    lqip_from_bytes(&bytes, options)
+}
+
+/// Generates the placeholder of an encoded image, its format being detected from its bytes.
+pub fn lqip_from_bytes(bytes: &[u8], options: &LqipOptions) -> Result<Lqip, LqipError> {
+    let image = image::load_from_memory(bytes).map_err(LqipError::Decode)?;
+    let (width, height) = (image.width(), image.height());
+
+    let size = options.size.max(1);
+    let mut small = image.resize(size, size, FilterType::Triangle);
+    if options.sigma > 0.0 {
+        small = small.blur(options.sigma);
+    }
+
+    let mut encoded = Vec::new();
+    match options.format {
+        LqipFormat::WebP => {
+            let rgba = small.to_rgba8();
+            WebPEncoder::new_lossless(&mut encoded).encode(
+                &rgba,
+                rgba.width(),
+                rgba.height(),
+                ExtendedColorType::Rgba8,
+            )
+        }
+        LqipFormat::Jpeg(quality) => {
+            JpegEncoder::new_with_quality(&mut encoded, quality.clamp(1, 100))
+                .encode_image(&DynamicImage::ImageRgb8(over_white(&small)))
+        }
+    }
+    .map_err(LqipError::Encode)?;
+
+    Ok(Lqip {
+        data_url: format!(
+            "data:{};base64,{}",
+            options.format.mime(),
+            STANDARD.encode(&encoded)
+        ),
+        width,
+        height,
+        dominant_color: dominant_color(&image),
+    })
+}
+
+/// Flattens the transparent pixels of `image` over a white background.
+fn over_white(image: &DynamicImage) -> image::RgbImage {
+    let rgba = image.to_rgba8();
+    image::RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+        let [r, g, b, a] = rgba.get_pixel(x, y).0;
+        let blend = |channel: u8| {
+            ((u32::from(channel) * u32::from(a) + 255 * (255 - u32::from(a))) / 255) as u8
+        };
+        image::Rgb([blend(r), blend(g), blend(b)])
+    })
+}
+
+/// Returns the most common color of `image`: the average of the largest bucket of similar
+/// colors, ignoring transparent pixels.
+fn dominant_color(image: &DynamicImage) -> [u8; 3] {
+    // A 64px thumbnail is enough to find the dominant color of any image, and bounds the work.
+    let rgba = image.thumbnail(64, 64).to_rgba8();
+    let mut buckets: HashMap<[u8; 3], (u32, [u32; 3])> = HashMap::new();
+    for pixel in rgba.pixels().filter(|pixel| pixel.0[3] >= 128) {
+        let [r, g, b, _] = pixel.0;
+        // Four bits per channel: close shades fall in the same bucket.
+        let (count, sum) = buckets.entry([r >> 4, g >> 4, b >> 4]).or_default();
+        *count += 1;
+        for (sum, channel) in sum.iter_mut().zip([r, g, b]) {
+            *sum += u32::from(channel);
+        }
+    }
+    buckets
+        .into_iter()
+        // Ties are broken on the bucket, so the result does not depend on the hash order.
+        .max_by_key(|(bucket, (count, _))| (*count, *bucket))
+        .map(|(_, (count, sum))| sum.map(|sum| (sum / count) as u8))
+        .unwrap_or([0, 0, 0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, Rgba, RgbaImage};
+    use std::io::Cursor;
+
+    fn png(image: RgbaImage) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    /// A 120x80 image, blue but for a red band on its left third.
+    fn banner() -> Vec<u8> {
+        png(RgbaImage::from_fn(120, 80, |x, _| {
+            if x < 40 {
+                Rgba([220, 38, 38, 255])
+            } else {
+                Rgba([30, 64, 175, 255])
+            }
+        }))
+    }
+
+    fn decoded(lqip: &Lqip) -> DynamicImage {
+        let (_, data) = lqip.data_url.split_once(";base64,").unwrap();
+        image::load_from_memory(&STANDARD.decode(data).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn keeps_the_intrinsic_size_and_aspect_ratio() {
+        let lqip = lqip_from_bytes(&banner(), &LqipOptions::default()).unwrap();
+        assert_eq!((lqip.width, lqip.height), (120, 80));
+        assert!(lqip.data_url.starts_with("data:image/webp;base64,"));
+        let small = decoded(&lqip);
+        assert_eq!((small.width(), small.height()), (16, 11));
+    }
+
+    #[test]
+    fn finds_the_dominant_color() {
+        let lqip = lqip_from_bytes(&banner(), &LqipOptions::default()).unwrap();
+        assert_eq!(lqip.dominant_color, [30, 64, 175]);
+        assert_eq!(lqip.color_css(), "#1e40af");
+        assert_eq!(
+            lqip.color::<()>(),
+            Placeholder::Color("#1e40af".to_string())
+        );
+
+        // Transparent pixels are ignored.
+        let logo = png(RgbaImage::from_fn(10, 10, |x, _| {
+            if x < 3 {
+                Rgba([22, 163, 74, 255])
+            } else {
+                Rgba([0, 0, 0, 0])
+            }
+        }));
+        let lqip = lqip_from_bytes(&logo, &LqipOptions::default()).unwrap();
+        assert_eq!(lqip.dominant_color, [22, 163, 74]);
+    }
+
+    #[test]
+    fn encodes_jpeg_over_white() {
+        let transparent = png(RgbaImage::from_pixel(8, 8, Rgba([0, 0, 0, 0])));
+        let options = LqipOptions::default()
+            .with_format(LqipFormat::Jpeg(80))
+            .with_sigma(0.0);
+        let lqip = lqip_from_bytes(&transparent, &options).unwrap();
+        assert!(lqip.data_url.starts_with("data:image/jpeg;base64,"));
+        let small = decoded(&lqip).to_rgb8();
+        assert!(small.pixels().all(|pixel| pixel.0.iter().all(|&c| c > 245)));
+    }
+
+    #[test]
+    fn writes_constants() {
+        let lqip = Lqip {
+            data_url: "data:image/webp;base64,AAAA".into(),
+            width: 800,
+            height: 600,
+            dominant_color: [255, 0, 0],
+        };
+        assert_eq!(
+            lqip.to_consts("HERO"),
+            "pub const HERO_BLUR_DATA_URL: &str = \"data:image/webp;base64,AAAA\";\n\
+             pub const HERO_WIDTH: u32 = 800;\n\
+             pub const HERO_HEIGHT: u32 = 600;\n\
+             pub const HERO_COLOR: &str = \"#ff0000\";\n"
+        );
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert!(matches!(
+            lqip_from_bytes(b"<html></html>", &LqipOptions::default()),
+            Err(LqipError::Decode(_))
+        ));
+        let error = lqip("does/not/exist.png").unwrap_err();
+        assert!(matches!(error, LqipError::Io { .. }));
+        assert!(
+            error
+                .to_string()
+                .starts_with("failed to read does/not/exist.png")
+        );
+    }
+}
@@ -1,4 +1,46 @@
+pub mod aspect_ratio;
+pub mod blurhash;
+pub mod canvas;
+pub mod core;
+pub mod decode;
+pub mod dimension;
+pub mod error;
+pub mod fallback;
+pub mod lazy;
+pub mod loader;
+#[cfg(any(
+    feature = "cloudinary",
+    feature = "imgix",
+    feature = "imgproxy",
+    feature = "thumbor",
+    feature = "cloudflare"
+))]
+pub mod loaders;
+pub mod observer;
+pub mod picture;
+pub mod placeholder;
+pub mod preload;
+pub mod sniff;
+pub mod srcset;
+pub mod static_image;
+pub mod status;
+pub mod thumbhash;
+pub mod transition;
+
+pub use aspect_ratio::AspectRatio;
+pub use core::LoadEvent;
+pub use dimension::Dimension;
+pub use error::ImageError;
+pub use fallback::{FallbackEvent, ImageSource};
+pub use lazy::{LazyConfig, ScrollRoot};
+pub use loader::{ImageLoader, Loader, LoaderProps};
+pub use picture::Source;
+pub use placeholder::Placeholder;
+pub use srcset::Breakpoints;
+pub use static_image::StaticImage;
+pub use status::{ImageStatus, UseImageOptions};
 use std::str::FromStr;
+pub use transition::{Easing, Transition, TransitionKind};
 
 /// Enum representing the layout of an image.
 ///
@@ -27,6 +69,9 @@ pub enum Layout {
 
     /// The image is scaled down to fit the container but does not scale up beyond its original size.
     ScaleDown,
+
+    /// The image fills the width of its container, whose height follows the CSS `aspect-ratio`.
+    AspectRatio,
 }
 
 impl Layout {
@@ -42,6 +87,7 @@ impl Layout {
             Layout::Auto => "auto",
             Layout::Stretch => "stretch",
             Layout::ScaleDown => "scale-down",
+            Layout::AspectRatio => "aspect-ratio",
         }
     }
 }
@@ -62,6 +108,7 @@ impl FromStr for Layout {
             "auto" => Ok(Layout::Auto),
             "stretch" => Ok(Layout::Stretch),
             "scale-down" => Ok(Layout::ScaleDown),
+            "aspect-ratio" => Ok(Layout::AspectRatio),
             _ => Err(()),
         }
     }
@@ -85,6 +132,11 @@ pub enum Decoding {
 
     /// Allow asynchronous decoding (non-blocking).
     Async,
+
+    /// Fetch and decode the image off-DOM with `HTMLImageElement.decode()`, and only render
+    /// its `src` once decoded. The blur placeholder stays in place until then, so large images
+    /// never show half-decoded. Rendered as `decoding="async"`.
+    Deferred,
 }
 
 impl Decoding {
@@ -95,7 +147,7 @@ impl Decoding {
         match self {
             Decoding::Auto => "auto",
             Decoding::Sync => "sync",
-            Decoding::Async => "async",
+            Decoding::Async | Decoding::Deferred => "async",
         }
     }
 }
@@ -105,12 +157,13 @@ impl FromStr for Decoding {
 
     /// Parses a string into a `Decoding` enum variant.
     ///
-    /// Accepts case-insensitive inputs like `"auto"`, `"sync"`, or `"async"`.
+    /// Accepts case-insensitive inputs like `"auto"`, `"sync"`, `"async"` or `"deferred"`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "auto" => Ok(Decoding::Auto),
             "sync" => Ok(Decoding::Sync),
             "async" => Ok(Decoding::Async),
+            "deferred" => Ok(Decoding::Deferred),
             _ => Err(()),
         }
     }
@@ -208,7 +261,7 @@ impl ObjectFit {
     }
 }
 
-#[derive(Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum CrossOrigin {
     Anonymous,
     UseCredentials,
@@ -226,7 +279,7 @@ impl CrossOrigin {
     }
 }
 
-#[derive(Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum FetchPriority {
     High,
     Low,
@@ -244,7 +297,7 @@ impl FetchPriority {
     }
 }
 
-#[derive(Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum Loading {
     Eager,
     Lazy,
@@ -0,0 +1,137 @@
+use super::dimension::Dimension;
+use std::fmt;
+use std::str::FromStr;
+
+/// The ratio of the width to the height of an image, e.g. `16 / 9`.
+///
+/// Reserves the space of a `Layout::Responsive` or `Layout::AspectRatio` image before it is
+/// loaded. When not set explicitly, it is derived from the `width` and `height`, then from a
+/// ThumbHash placeholder.
+///
+/// # Example
+/// ```rust
+/// use image_rs::AspectRatio;
+///
+/// let widescreen: AspectRatio = "16/9".parse().unwrap();
+/// assert_eq!(widescreen, AspectRatio::new(16.0, 9.0));
+/// assert_eq!(widescreen.as_css(), "16 / 9");
+/// assert_eq!(widescreen.padding_top(), "56.25%");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AspectRatio {
+    /// The width term of the ratio.
+    pub width: f64,
+
+    /// The height term of the ratio.
+    pub height: f64,
+}
+
+impl AspectRatio {
+    /// A square.
+    pub const SQUARE: AspectRatio = AspectRatio::new(1.0, 1.0);
+
+    /// Creates the ratio `width / height`.
+    pub const fn new(width: f64, height: f64) -> Self {
+        AspectRatio { width, height }
+    }
+
+    /// Creates a ratio from its value, width over height.
+    pub const fn from_ratio(ratio: f64) -> Self {
+        AspectRatio::new(ratio, 1.0)
+    }
+
+    /// Returns the ratio of the `width` and `height` of an image, `None` unless both are
+    /// positive lengths of the same unit other than percentages.
+    pub fn from_dimensions(width: Dimension, height: Dimension) -> Option<Self> {
+        Dimension::lengths(width, height).map(|(width, height)| AspectRatio::new(width, height))
+    }
+
+    /// Returns `true` if both terms are positive and finite.
+    pub fn is_valid(&self) -> bool {
+        [self.width, self.height]
+            .iter()
+            .all(|term| term.is_finite() && *term > 0.0)
+    }
+
+    /// Returns the value of the ratio, width over height.
+    pub fn ratio(&self) -> f64 {
+        self.width / self.height
+    }
+
+    /// Returns the CSS `aspect-ratio` value, e.g. `16 / 9`.
+    pub fn as_css(&self) -> String {
+        format!("{} / {}", self.width, self.height)
+    }
+
+    /// Returns the `padding-top` reserving the ratio in browsers without `aspect-ratio`.
+    pub fn padding_top(&self) -> String {
+        format!("{}%", self.height / self.width * 100.0)
+    }
+}
+
+impl Default for AspectRatio {
+    fn default() -> Self {
+        AspectRatio::SQUARE
+    }
+}
+
+impl fmt::Display for AspectRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.as_css())
+    }
+}
+
+impl FromStr for AspectRatio {
+    type Err = ();
+
+    /// Parses `16/9`, `16:9`, `16 / 9` or a single ratio such as `1.5`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let term = |term: &str| term.trim().parse::<f64>().map_err(|_| ());
+        let ratio = match s.split_once(['/', ':']) {
+            Some((width, height)) => AspectRatio::new(term(width)?, term(height)?),
+            None => AspectRatio::from_ratio(term(s)?),
+        };
+        if ratio.is_valid() { Ok(ratio) } else { Err(()) }
+    }
+}
+
+impl From<(u32, u32)> for AspectRatio {
+    fn from((width, height): (u32, u32)) -> Self {
+        AspectRatio::new(f64::from(width), f64::from(height))
+    }
+}
+
+impl From<f64> for AspectRatio {
+    fn from(ratio: f64) -> Self {
+        AspectRatio::from_ratio(ratio)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ratios() {
+        assert_eq!("16/9".parse(), Ok(AspectRatio::new(16.0, 9.0)));
+        assert_eq!(" 4 : 3 ".parse(), Ok(AspectRatio::new(4.0, 3.0)));
+        assert_eq!("1.5".parse(), Ok(AspectRatio::from_ratio(1.5)));
+        for invalid in ["", "16/", "0/9", "16/0", "-1", "inf", "wide"] {
+            assert_eq!(invalid.parse::<AspectRatio>(), Err(()), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn derives_from_dimensions() {
+        let ratio =
+            AspectRatio::from_dimensions(Dimension::Px(800.0), Dimension::Px(600.0)).unwrap();
+        assert_eq!(ratio.padding_top(), "75%");
+        assert_eq!(ratio.as_css(), "800 / 600");
+        assert!((ratio.ratio() - 4.0 / 3.0).abs() < 1e-9);
+        assert_eq!(
+            AspectRatio::from_dimensions(Dimension::Auto, Dimension::Px(600.0)),
+            None
+        );
+        assert_eq!(AspectRatio::from((1200, 800)).as_css(), "1200 / 800");
+    }
+}
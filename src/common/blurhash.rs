@@ -0,0 +1,228 @@
+//! A pure-Rust [BlurHash](https://blurha.sh) decoder.
+//!
+//! A BlurHash packs the average color and a few cosine components of an image into a short
+//! string. Decoding it gives a tiny RGBA image, rendered as the placeholder underlay with
+//! [`rgba_to_data_url`](super::canvas::rgba_to_data_url).
+
+use std::f64::consts::PI;
+use std::fmt;
+
+/// Characters of the base 83 encoding of BlurHash, in order.
+const BASE83: &[u8] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+/// Error returned when a BlurHash cannot be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlurHashError {
+    /// The hash does not have the length announced by its first character.
+    Length {
+        /// The expected number of characters.
+        expected: usize,
+        /// The actual number of characters.
+        actual: usize,
+    },
+
+    /// The hash contains a character outside of the base 83 alphabet.
+    Character {
+        /// The index of the character in the hash.
+        index: usize,
+    },
+}
+
+impl fmt::Display for BlurHashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlurHashError::Length { expected, actual } => {
+                write!(f, "invalid BlurHash length {actual}, expected {expected}")
+            }
+            BlurHashError::Character { index } => {
+                write!(f, "invalid BlurHash character at index {index}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BlurHashError {}
+
+/// Decodes `hash[start..end]` as a base 83 number.
+fn decode83(hash: &[u8], start: usize, end: usize) -> Result<u32, BlurHashError> {
+    (start..end).try_fold(0, |value, index| {
+        let digit = BASE83
+            .iter()
+            .position(|c| Some(c) == hash.get(index))
+            .ok_or(BlurHashError::Character { index })?;
+        Ok(value * 83 + digit as u32)
+    })
+}
+
+fn srgb_to_linear(value: u32) -> f64 {
+    let value = f64::from(value) / 255.0;
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f64) -> u8 {
+    let value = value.clamp(0.0, 1.0);
+    let srgb = if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    };
+    (srgb * 255.0 + 0.5) as u8
+}
+
+fn sign_pow(value: f64, exponent: f64) -> f64 {
+    value.abs().powf(exponent).copysign(value)
+}
+
+/// Returns the number of horizontal and vertical components of `hash`, validating its length.
+pub fn components(hash: &str) -> Result<(u32, u32), BlurHashError> {
+    let bytes = hash.as_bytes();
+    if bytes.len() < 6 {
+        return Err(BlurHashError::Length {
+            expected: 6,
+            actual: bytes.len(),
+        });
+    }
+    let size = decode83(bytes, 0, 1)?;
+    let (x, y) = (size % 9 + 1, size / 9 + 1);
+    let expected = 4 + 2 * (x * y) as usize;
+    if bytes.len() != expected {
+        return Err(BlurHashError::Length {
+            expected,
+            actual: bytes.len(),
+        });
+    }
+    Ok((x, y))
+}
+
+/// Returns the average color of the image, stored as is in `hash`.
+pub fn average_color(hash: &str) -> Result<[u8; 4], BlurHashError> {
+    components(hash)?;
+    let [_, r, g, b] = decode83(hash.as_bytes(), 2, 6)?.to_be_bytes();
+    Ok([r, g, b, u8::MAX])
+}
+
+/// Decodes `hash` into a `width` x `height` RGBA buffer, row by row.
+///
+/// `punch` scales the contrast of the components; `1.0` renders the hash as encoded. A
+/// 32x32 image is plenty for a placeholder, as it is stretched and smooth anyway.
+///
+/// # Errors
+/// Returns a [`BlurHashError`] if `hash` is malformed.
+pub fn decode(hash: &str, width: u32, height: u32, punch: f64) -> Result<Vec<u8>, BlurHashError> {
+    let (num_x, num_y) = components(hash)?;
+    let bytes = hash.as_bytes();
+    let max_value = f64::from(decode83(bytes, 1, 2)? + 1) / 166.0 * punch;
+
+    let colors = (0..(num_x * num_y) as usize)
+        .map(|index| {
+            if index == 0 {
+                let value = decode83(bytes, 2, 6)?;
+                return Ok([
+                    srgb_to_linear(value >> 16),
+                    srgb_to_linear((value >> 8) & 255),
+                    srgb_to_linear(value & 255),
+                ]);
+            }
+            let start = 4 + index * 2;
+            let value = decode83(bytes, start, start + 2)?;
+            let channel =
+                |quantized: u32| sign_pow((f64::from(quantized) - 9.0) / 9.0, 2.0) * max_value;
+            Ok([
+                channel(value / (19 * 19)),
+                channel((value / 19) % 19),
+                channel(value % 19),
+            ])
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+    for y in 0..height {
+        for x in 0..width {
+            let mut rgb = [0.0; 3];
+            for j in 0..num_y {
+                let basis_y = (PI * f64::from(y) * f64::from(j) / f64::from(height)).cos();
+                for i in 0..num_x {
+                    let basis =
+                        (PI * f64::from(x) * f64::from(i) / f64::from(width)).cos() * basis_y;
+                    let color = colors[(i + j * num_x) as usize];
+                    for (channel, value) in rgb.iter_mut().zip(color) {
+                        *channel += value * basis;
+                    }
+                }
+            }
+            pixels.extend(rgb.map(linear_to_srgb));
+            pixels.push(u8::MAX);
+        }
+    }
+    Ok(pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_malformed_hashes() {
+        assert_eq!(
+            decode("LEHV6n", 4, 4, 1.0),
+            Err(BlurHashError::Length {
+                expected: 28,
+                actual: 6
+            })
+        );
+        assert_eq!(
+            decode("LEHV6nWB2yk8pyo0adR*.7kCMdn\"", 4, 4, 1.0),
+            Err(BlurHashError::Character { index: 27 })
+        );
+        assert!(components("").is_err());
+    }
+
+    #[test]
+    fn decodes_a_single_component_to_its_average_color() {
+        // One component, the color 0x336699.
+        let hash = "005?}k";
+        assert_eq!(average_color(hash), Ok([0x33, 0x66, 0x99, 255]));
+        let pixels = decode(hash, 3, 2, 1.0).unwrap();
+        assert_eq!(pixels.len(), 3 * 2 * 4);
+        for pixel in pixels.chunks(4) {
+            assert_eq!(pixel, [0x33, 0x66, 0x99, 255]);
+        }
+    }
+
+    #[test]
+    fn decodes_reference_hashes() {
+        // Hashes of the reference implementation, with the pixels it decodes at the top left,
+        // center and bottom right of a 32x32 image.
+        let references = [
+            (
+                "LEHV6nWB2yk8pyo0adR*.7kCMdnj",
+                [[135, 164, 177], [158, 125, 108], [133, 142, 147]],
+            ),
+            (
+                "LKO2?U%2Tw=w]~RBVZRi};RPxuwH",
+                [[251, 193, 161], [196, 161, 159], [192, 191, 182]],
+            ),
+        ];
+        for (hash, expected) in references {
+            assert_eq!(components(hash), Ok((4, 3)));
+            let pixels = decode(hash, 32, 32, 1.0).unwrap();
+            assert_eq!(pixels.len(), 32 * 32 * 4);
+            let pixel = |x: usize, y: usize| {
+                let start = (y * 32 + x) * 4;
+                [pixels[start], pixels[start + 1], pixels[start + 2]]
+            };
+            assert_eq!(
+                [pixel(0, 0), pixel(16, 16), pixel(31, 31)],
+                expected,
+                "{hash}"
+            );
+            assert!(pixels.chunks(4).all(|pixel| pixel[3] == 255));
+        }
+        assert_eq!(average_color(references[0].0), Ok([151, 150, 149, 255]));
+    }
+}
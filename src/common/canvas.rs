@@ -0,0 +1,20 @@
+use web_sys::wasm_bindgen::{Clamped, JsCast};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, ImageData};
+
+/// Paints an RGBA buffer of `width` x `height` pixels on an off-DOM canvas and returns it as a
+/// PNG data URL, e.g. to render a decoded hash as the background of a placeholder.
+///
+/// Returns `None` outside of a browser or if the buffer does not match the size.
+pub fn rgba_to_data_url(rgba: &[u8], width: u32, height: u32) -> Option<String> {
+    if !cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    let document = web_sys::window()?.document()?;
+    let canvas: HtmlCanvasElement = document.create_element("canvas").ok()?.dyn_into().ok()?;
+    canvas.set_width(width);
+    canvas.set_height(height);
+    let context: CanvasRenderingContext2d = canvas.get_context("2d").ok()??.dyn_into().ok()?;
+    let image = ImageData::new_with_u8_clamped_array_and_sh(Clamped(rgba), width, height).ok()?;
+    context.put_image_data(&image, 0.0, 0.0).ok()?;
+    canvas.to_data_url().ok()
+}
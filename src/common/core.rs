@@ -0,0 +1,966 @@
+//! Framework-agnostic loading logic shared by the Yew, Dioxus and Leptos adapters.
+//!
+//! The adapters only translate their props into [`ImageOptions`], keep an [`ImageState`] in
+//! their own reactive primitive and feed it [`ImageEvent`]s. Everything that ends up in the
+//! DOM, from the `src` to the container styles, is computed here so the three components
+//! behave the same way.
+
+use super::aspect_ratio::AspectRatio;
+use super::dimension::Dimension;
+use super::loader::{DEFAULT_LOADER_WIDTH, parse_quality};
+use super::picture::Source;
+use super::placeholder::Placeholder;
+use super::srcset::{Breakpoints, generate_img_attrs};
+use super::static_image::StaticImage;
+use super::transition::{Transition, TransitionKind};
+use super::{
+    Decoding, FetchPriority, ImageError, Layout, Loader, LoaderProps, Loading, ObjectFit, Position,
+};
+
+/// Load state of an image.
+///
+/// ```text
+/// Idle --Visible--> InView --LoadStart--> Loading --Load--> Decoded
+///                                            |
+///                                          Error
+///                                            v
+///                          Fallback (if any) --Load--> Decoded
+///                                            |  ^
+///                                          Error |
+///                                            |  +-- next fallback of the chain, if any
+///                                            v
+///                                          Failed
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageState {
+    /// Waiting for the image to scroll into view. No `src` is rendered yet.
+    #[default]
+    Idle,
+
+    /// The image entered the viewport and is about to be requested.
+    InView,
+
+    /// The image is being fetched and decoded.
+    Loading,
+
+    /// The image loaded and decoded successfully.
+    Decoded,
+
+    /// The image failed and a fallback of the chain is being fetched or displayed.
+    Fallback,
+
+    /// The image and all of its fallbacks failed to load.
+    Failed,
+}
+
+/// Events driving an [`ImageState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageEvent {
+    /// The image entered the viewport.
+    Visible,
+
+    /// The browser started requesting the image.
+    LoadStart,
+
+    /// The `<img>` fired its `load` event.
+    Load,
+
+    /// The `<img>` fired its `error` event, or the fallback could not be fetched.
+    Error {
+        /// Whether a fallback image remains in the chain to recover from the error.
+        has_fallback: bool,
+    },
+
+    /// The `src` changed, the image starts over.
+    Reset {
+        /// Whether the new image waits for the viewport.
+        lazy: bool,
+    },
+}
+
+/// Reported through the `on_load` callback of an `Image` once a source is decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadEvent {
+    /// The URL of the decoded image, the candidate picked from the `srcset` if any.
+    pub url: String,
+
+    /// The index of the displayed fallback in the chain, `None` for `src`.
+    pub fallback: Option<usize>,
+
+    /// The intrinsic width of the image, in pixels.
+    pub natural_width: u32,
+
+    /// The intrinsic height of the image, in pixels.
+    pub natural_height: u32,
+}
+
+impl LoadEvent {
+    /// Reads the decoded image from its `<img>` element.
+    pub fn from_element(img: &web_sys::HtmlImageElement, fallback: Option<usize>) -> Self {
+        LoadEvent {
+            url: img.current_src(),
+            fallback,
+            natural_width: img.natural_width(),
+            natural_height: img.natural_height(),
+        }
+    }
+}
+
+impl ImageState {
+    /// Returns the state an image starts in.
+    ///
+    /// Lazy images wait for the viewport in [`ImageState::Idle`], the others start loading
+    /// right away.
+    pub fn initial(lazy: bool) -> Self {
+        if lazy {
+            ImageState::Idle
+        } else {
+            ImageState::Loading
+        }
+    }
+
+    /// Returns the state reached after `event`.
+    ///
+    /// Events that make no sense in the current state are ignored.
+    pub fn next(self, event: ImageEvent) -> Self {
+        use ImageEvent::*;
+        use ImageState::*;
+
+        match (self, event) {
+            (_, Reset { lazy }) => ImageState::initial(lazy),
+            (Idle, Visible) => InView,
+            (Idle | InView, LoadStart) => Loading,
+            (Loading | Fallback, Load) => Decoded,
+            (Loading | Fallback, Error { has_fallback }) if has_fallback => Fallback,
+            (Loading | Fallback, Error { .. }) => Failed,
+            (state, _) => state,
+        }
+    }
+
+    /// Returns `true` once the image may be requested, i.e. its `src` should be rendered.
+    pub fn has_src(self) -> bool {
+        !matches!(self, ImageState::Idle)
+    }
+
+    /// Returns `true` once the image reached a final state.
+    pub fn is_settled(self) -> bool {
+        matches!(self, ImageState::Decoded | ImageState::Failed)
+    }
+}
+
+/// Inputs of the image, borrowed from the props of an adapter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageOptions<'a, V = ()> {
+    /// The source URL of the image.
+    pub src: &'a str,
+    /// The width of the image.
+    pub width: Dimension,
+    /// The height of the image.
+    pub height: Dimension,
+    /// The quality forwarded to the loader.
+    pub quality: &'a str,
+    /// The `sizes` attribute.
+    pub sizes: &'a str,
+    /// A hand-written `srcset`, taking precedence over the generated one.
+    pub srcset: &'a str,
+    /// The `<source>` elements of the enclosing `<picture>`, empty for a bare `<img>`.
+    pub sources: &'a [Source],
+    /// Additional inline styles of the `<img>`.
+    pub style: &'a str,
+    /// What is rendered behind the image until it is decoded.
+    pub placeholder: &'a Placeholder<V>,
+    /// A bundled image providing the `src`, size and placeholder left unset.
+    pub static_image: Option<StaticImage>,
+    /// The ratio reserving the space of the image, derived from the size when `None`.
+    pub aspect_ratio: Option<AspectRatio>,
+    /// How the image is laid out inside its container.
+    pub layout: Layout,
+    /// The `object-fit` of the image.
+    pub object_fit: ObjectFit,
+    /// The `object-position` of the image.
+    pub object_position: Position,
+    /// The `loading` attribute.
+    pub loading: Loading,
+    /// The `fetchpriority` attribute.
+    pub fetchpriority: FetchPriority,
+    /// How the image is decoded.
+    pub decoding: Decoding,
+    /// The transition between the placeholder and the decoded image.
+    pub transition: Transition,
+    /// Whether the user asked for reduced motion, which disables the transition.
+    pub reduced_motion: bool,
+    /// Whether the image is a priority image.
+    pub priority: bool,
+    /// The loader building the image URLs.
+    pub loader: Option<&'a Loader>,
+    /// The widths used to generate the `srcset`.
+    pub breakpoints: &'a Breakpoints,
+    /// Whether the loader is skipped.
+    pub unoptimized: bool,
+}
+
+/// Everything an adapter needs to render an image, computed from [`ImageOptions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageAttributes {
+    /// The resolved `src`.
+    pub src: String,
+    /// The resolved `srcset`, possibly empty.
+    pub srcset: String,
+    /// The resolved `sizes`, possibly empty.
+    pub sizes: String,
+    /// The `<source>` elements of the `<picture>`, with the `sizes` of the image when unset.
+    pub sources: Vec<Source>,
+    /// The `width` attribute of the `<img>`.
+    pub width: String,
+    /// The `height` attribute of the `<img>`.
+    pub height: String,
+    /// The effective `loading` attribute.
+    pub loading: Loading,
+    /// The effective `fetchpriority` attribute.
+    pub fetchpriority: FetchPriority,
+    /// How the image is decoded.
+    pub decoding: Decoding,
+    /// The inline style of the `<img>`, without the placeholder.
+    pub img_style: String,
+    /// The placeholder painted as a background until the image is decoded, if any.
+    pub underlay_style: Option<String>,
+    /// Whether the placeholder is a view of the framework, rendered in its own layer.
+    pub custom_placeholder: bool,
+    /// The transition played once the image is decoded, none if motion is reduced.
+    pub transition: Transition,
+    /// The inline style of the outer `<span>`.
+    pub container_style: String,
+    /// The inline style of an extra `<span>` wrapping the `<img>`, if the layout needs one.
+    pub wrapper_style: Option<&'static str>,
+    /// The inline style of an empty `<span>` reserving space, if the layout needs one.
+    pub spacer_style: Option<String>,
+}
+
+impl<V> ImageOptions<'_, V> {
+    /// Returns `true` if the image waits for the viewport before being requested.
+    pub fn is_lazy(&self) -> bool {
+        !self.priority && self.loading != Loading::Eager
+    }
+
+    /// Computes the attributes and styles of the image.
+    pub fn attributes(&self) -> ImageAttributes {
+        let src = match self.static_image {
+            Some(image) if self.src.is_empty() => image.src,
+            _ => self.src,
+        };
+        let (width, height) = match self.static_image {
+            Some(image) if self.width.is_auto() && self.height.is_auto() => {
+                (Dimension::from(image.width), Dimension::from(image.height))
+            }
+            _ => (self.width, self.height),
+        };
+        let underlay_style = match (self.placeholder, self.static_image) {
+            (Placeholder::Empty, Some(image)) => image
+                .placeholder::<()>()
+                .underlay_style(self.object_position, self.reduced_motion),
+            (placeholder, _) => {
+                placeholder.underlay_style(self.object_position, self.reduced_motion)
+            }
+        };
+        let aspect_ratio = self
+            .aspect_ratio
+            .filter(AspectRatio::is_valid)
+            .or_else(|| AspectRatio::from_dimensions(width, height))
+            .or_else(|| self.placeholder.aspect_ratio().map(AspectRatio::from_ratio))
+            .filter(AspectRatio::is_valid);
+        let spacer_style = match self.layout {
+            Layout::Responsive => Some(format!(
+                "display: block; padding-top: {};",
+                padding_top(aspect_ratio)
+            )),
+            // Only sizes the container in browsers without `aspect-ratio`; elsewhere both
+            // agree, so the height is never doubled.
+            Layout::AspectRatio => Some(format!(
+                "display: block; float: left; padding-top: {};",
+                padding_top(aspect_ratio)
+            )),
+            _ => None,
+        };
+
+        let pixel_width = width.pixels();
+        // Without a rendered width, the height is derived at the largest width and scaled
+        // down to each candidate.
+        let loader_width = pixel_width.unwrap_or(DEFAULT_LOADER_WIDTH);
+        let attrs = generate_img_attrs(
+            LoaderProps {
+                src,
+                width: loader_width,
+                height: aspect_ratio
+                    .map(|ratio| (f64::from(loader_width) / ratio.ratio()).round() as u32)
+                    .filter(|height| *height > 0),
+                quality: parse_quality(self.quality),
+                object_fit: self.object_fit,
+                object_position: self.object_position,
+            },
+            pixel_width,
+            self.layout,
+            self.sizes,
+            self.breakpoints,
+            self.loader,
+            self.unoptimized,
+        );
+        let srcset = if self.srcset.is_empty() {
+            attrs.srcset
+        } else {
+            self.srcset.to_string()
+        };
+        let (loading, fetchpriority) = if self.priority {
+            (Loading::Eager, FetchPriority::High)
+        } else {
+            (self.loading.clone(), self.fetchpriority.clone())
+        };
+        let sources = self
+            .sources
+            .iter()
+            .map(|source| Source {
+                sizes: if source.sizes.is_empty() {
+                    attrs.sizes.clone()
+                } else {
+                    source.sizes.clone()
+                },
+                ..source.clone()
+            })
+            .collect::<Vec<_>>();
+        // The browser picks the source of a `<picture>`, which cannot be decoded off-DOM.
+        let decoding = match self.decoding {
+            Decoding::Deferred if !sources.is_empty() => Decoding::Async,
+            decoding => decoding,
+        };
+        ImageAttributes {
+            src: attrs.src,
+            srcset,
+            sizes: attrs.sizes,
+            sources,
+            width: width.attribute(),
+            height: height.attribute(),
+            loading,
+            fetchpriority,
+            decoding,
+            img_style: img_style(self, width, height),
+            underlay_style,
+            custom_placeholder: self.placeholder.is_custom(),
+            transition: self.transition.effective(self.reduced_motion),
+            container_style: container_style(self.layout, aspect_ratio),
+            wrapper_style: match self.layout {
+                Layout::Intrinsic => Some("max-width: 100%;"),
+                _ => None,
+            },
+            spacer_style,
+        }
+    }
+}
+
+impl ImageAttributes {
+    /// Returns `true` if the image is decoded off-DOM before its `src` is rendered.
+    pub fn is_deferred(&self) -> bool {
+        self.decoding == Decoding::Deferred
+    }
+
+    /// Returns `true` while a deferred image is being decoded off-DOM, i.e. the adapter should
+    /// run [`decode_image`](super::decode::decode_image) instead of rendering the `src`.
+    pub fn awaits_decode(&self, state: ImageState, fallback: Option<&str>) -> bool {
+        self.is_deferred() && state == ImageState::Loading && fallback.is_none()
+    }
+
+    /// Returns the `src` to render in `state`, `None` while the image is idle or decoded
+    /// off-DOM.
+    ///
+    /// Once a fallback has been validated, it replaces the original source.
+    pub fn current_src(&self, state: ImageState, fallback: Option<&str>) -> Option<String> {
+        if !state.has_src() || self.awaits_decode(state, fallback) {
+            return None;
+        }
+        Some(fallback.unwrap_or(&self.src).to_string())
+    }
+
+    /// Returns the `srcset` to render in `state`.
+    ///
+    /// The `srcset` is dropped once a fallback is displayed, otherwise the browser would
+    /// keep picking the failing candidates over the fallback `src`.
+    pub fn current_srcset(&self, state: ImageState, fallback: Option<&str>) -> Option<String> {
+        if !state.has_src()
+            || self.awaits_decode(state, fallback)
+            || fallback.is_some()
+            || self.srcset.is_empty()
+        {
+            return None;
+        }
+        Some(self.srcset.clone())
+    }
+
+    /// Returns the `<source>` elements to render in `state`, none while the image is idle.
+    ///
+    /// Like the `srcset`, they are dropped once a fallback is displayed, otherwise the browser
+    /// would keep picking them over the fallback `src`.
+    pub fn current_sources(&self, state: ImageState, fallback: Option<&str>) -> &[Source] {
+        if !state.has_src() || fallback.is_some() {
+            return &[];
+        }
+        &self.sources
+    }
+
+    /// Returns `true` if the placeholder is rendered in a layer behind the `<img>` rather than
+    /// as its background: for custom views, and for fades, which hide the `<img>` itself.
+    pub fn has_placeholder_layer(&self) -> bool {
+        self.custom_placeholder || self.transition.fades() && self.underlay_style.is_some()
+    }
+
+    /// Returns the inline style of the `<img>` in `state`.
+    ///
+    /// The placeholder is removed once the image is decoded, so it never shows through the
+    /// full image. With a fade, the image stays transparent until then.
+    pub fn current_style(&self, state: ImageState) -> String {
+        let revealed = state.is_settled();
+        let transition = match self.transition.kind {
+            TransitionKind::None => String::new(),
+            TransitionKind::Blur if revealed => {
+                format!("{} filter: blur(0);", self.transition.css("filter"))
+            }
+            TransitionKind::Blur => {
+                format!("{} filter: blur(20px);", self.transition.css("filter"))
+            }
+            TransitionKind::Fade | TransitionKind::Crossfade => format!(
+                "{} opacity: {};",
+                self.transition.css("opacity"),
+                u8::from(revealed)
+            ),
+        };
+        let underlay = self
+            .underlay_style
+            .as_deref()
+            .filter(|_| !revealed && !self.has_placeholder_layer());
+        // Keeps the image above the placeholder layer while it fades in.
+        let above_layer = (self.transition.fades() && self.has_placeholder_layer())
+            .then_some("position: relative;");
+        // Inline styles set through the `style` prop come last and take precedence.
+        [
+            Some(transition.as_str()),
+            underlay,
+            above_layer,
+            Some(&self.img_style),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|style| !style.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+
+    /// Returns the inline style of the placeholder layer rendered behind the `<img>` in
+    /// `state`, `None` when there is no layer or once it can be removed.
+    ///
+    /// The layer stays until the image is decoded and, with a fade, until `transitioned`
+    /// reports the end of its transition. A crossfade fades the layer out meanwhile.
+    pub fn placeholder_style(&self, state: ImageState, transitioned: bool) -> Option<String> {
+        let revealed = state.is_settled();
+        let fades = self.transition.fades();
+        if !self.has_placeholder_layer() || revealed && (transitioned || !fades) {
+            return None;
+        }
+        let mut style = PLACEHOLDER_LAYER.to_string();
+        if let Some(underlay) = &self.underlay_style {
+            style.push(' ');
+            style.push_str(underlay);
+        }
+        if self.transition.kind == TransitionKind::Crossfade {
+            style.push_str(&format!(
+                " {} opacity: {};",
+                self.transition.css("opacity"),
+                u8::from(!revealed)
+            ));
+        }
+        Some(style)
+    }
+
+    /// Returns the error to report when the `<img>` fires its `error` event while displaying
+    /// `fallback`.
+    pub fn load_error(&self, fallback: Option<&str>) -> ImageError {
+        match fallback {
+            Some(url) => ImageError::Fallback {
+                url: url.to_string(),
+            },
+            None => ImageError::Decode {
+                url: self.src.clone(),
+            },
+        }
+    }
+}
+
+/// Builds the inline style of the `<img>`, without the placeholder.
+///
+/// Lengths that are not in pixels, hence not `width` and `height` attributes, are applied
+/// as CSS, except where the layout sizes the image itself.
+fn img_style<V>(options: &ImageOptions<'_, V>, width: Dimension, height: Dimension) -> String {
+    let mut style = format!(
+        "object-fit: {}; object-position: {};",
+        options.object_fit.as_str(),
+        options.object_position.as_str()
+    );
+    match options.layout {
+        Layout::Stretch => style.push_str(" width: 100%; height: 100%;"),
+        // Covers the space reserved by the container, instead of adding to it.
+        Layout::Responsive | Layout::AspectRatio => {
+            style.push_str(" position: absolute; top: 0; left: 0; width: 100%; height: 100%;")
+        }
+        Layout::Fill => {}
+        Layout::Intrinsic | Layout::Fixed | Layout::Auto | Layout::ScaleDown => {
+            for (property, dimension) in [("width", width), ("height", height)] {
+                if let Some(css) = dimension.as_css().filter(|_| dimension.pixels().is_none()) {
+                    style.push_str(&format!(" {property}: {css};"));
+                }
+            }
+        }
+    }
+    if !options.style.is_empty() {
+        style.push(' ');
+        style.push_str(options.style);
+    }
+    style
+}
+
+/// The placeholder layer, covering the container behind the `<img>`.
+const PLACEHOLDER_LAYER: &str =
+    "position: absolute; top: 0; left: 0; width: 100%; height: 100%; pointer-events: none;";
+
+/// Returns the inline style of the outer `<span>` for a layout.
+///
+/// `aspect_ratio` only matters to `Layout::AspectRatio`, which sizes the container with the
+/// CSS `aspect-ratio` property, a square when unknown.
+pub fn container_style(layout: Layout, aspect_ratio: Option<AspectRatio>) -> String {
+    let style = match layout {
+        Layout::AspectRatio => {
+            return format!(
+                "display: flow-root; position: relative; width: 100%; aspect-ratio: {};",
+                aspect_ratio.unwrap_or_default().as_css()
+            );
+        }
+        Layout::Fill => "display: block; position: absolute; top: 0; left: 0; bottom: 0; right: 0;",
+        Layout::Responsive => "display: block; position: relative;",
+        Layout::Intrinsic => "display: inline-block; position: relative; max-width: 100%;",
+        Layout::Fixed | Layout::Auto => "display: inline-block; position: relative;",
+        Layout::Stretch => "display: block; width: 100%; height: 100%; position: relative;",
+        Layout::ScaleDown => {
+            "display: inline-block; position: relative; max-width: 100%; max-height: 100%;"
+        }
+    };
+    style.to_string()
+}
+
+/// Returns the `padding-top` reserving the aspect ratio of a responsive image.
+///
+/// Falls back to a square (`100%`) when the ratio is unknown or invalid instead of panicking.
+pub fn padding_top(aspect_ratio: Option<AspectRatio>) -> String {
+    aspect_ratio
+        .filter(AspectRatio::is_valid)
+        .map_or_else(|| "100%".to_string(), |ratio| ratio.padding_top())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Options for an eager, unoptimized `/hero.jpg` with every other prop left unset.
+    fn options<'a>(breakpoints: &'a Breakpoints) -> ImageOptions<'a> {
+        ImageOptions {
+            src: "/hero.jpg",
+            width: Dimension::Auto,
+            height: Dimension::Auto,
+            quality: "",
+            sizes: "",
+            srcset: "",
+            sources: &[],
+            style: "",
+            placeholder: &Placeholder::Empty,
+            static_image: None,
+            aspect_ratio: None,
+            layout: Layout::Fixed,
+            object_fit: ObjectFit::Cover,
+            object_position: Position::Center,
+            loading: Loading::Eager,
+            fetchpriority: FetchPriority::Auto,
+            decoding: Decoding::Auto,
+            transition: Transition::default(),
+            reduced_motion: false,
+            priority: false,
+            loader: None,
+            breakpoints,
+            unoptimized: true,
+        }
+    }
+
+    #[test]
+    fn lazy_image_goes_through_every_state() {
+        let state = ImageState::initial(true);
+        assert_eq!(state, ImageState::Idle);
+        assert!(!state.has_src());
+
+        let state = state.next(ImageEvent::Visible);
+        assert_eq!(state, ImageState::InView);
+        let state = state.next(ImageEvent::LoadStart);
+        assert_eq!(state, ImageState::Loading);
+        let state = state.next(ImageEvent::Load);
+        assert_eq!(state, ImageState::Decoded);
+        assert!(state.is_settled());
+
+        let state = state.next(ImageEvent::Reset { lazy: true });
+        assert_eq!(state, ImageState::Idle, "new src");
+    }
+
+    #[test]
+    fn errors_go_through_the_fallback() {
+        let state = ImageState::initial(false).next(ImageEvent::Error { has_fallback: true });
+        assert_eq!(state, ImageState::Fallback);
+        assert_eq!(
+            state.next(ImageEvent::Load),
+            ImageState::Decoded,
+            "fallback loaded"
+        );
+        assert_eq!(
+            state.next(ImageEvent::Error { has_fallback: true }),
+            ImageState::Fallback,
+            "next fallback"
+        );
+        assert_eq!(
+            state.next(ImageEvent::Error {
+                has_fallback: false
+            }),
+            ImageState::Failed,
+            "chain exhausted"
+        );
+        assert_eq!(
+            ImageState::Loading.next(ImageEvent::Error {
+                has_fallback: false
+            }),
+            ImageState::Failed
+        );
+    }
+
+    #[test]
+    fn padding_top_never_panics() {
+        assert_eq!(padding_top(Some(AspectRatio::new(4.0, 3.0))), "75%");
+        assert_eq!(padding_top(Some(AspectRatio::from_ratio(0.5))), "200%");
+        assert_eq!(padding_top(Some(AspectRatio::from_ratio(0.0))), "100%");
+        assert_eq!(padding_top(Some(AspectRatio::new(0.0, 600.0))), "100%");
+        assert_eq!(padding_top(None), "100%");
+    }
+
+    #[test]
+    fn aspect_ratio_layout_reserves_the_space() {
+        let breakpoints = Breakpoints::default();
+        let mut options = ImageOptions {
+            aspect_ratio: Some(AspectRatio::new(16.0, 9.0)),
+            layout: Layout::AspectRatio,
+            ..options(&breakpoints)
+        };
+        let attrs = options.attributes();
+        assert!(attrs.container_style.contains("aspect-ratio: 16 / 9;"));
+        assert!(
+            attrs
+                .spacer_style
+                .as_deref()
+                .unwrap()
+                .contains("padding-top: 56.25%;")
+        );
+        assert!(
+            attrs
+                .current_style(ImageState::Loading)
+                .contains("position: absolute; top: 0; left: 0; width: 100%; height: 100%;"),
+            "covers the reserved space instead of adding to it"
+        );
+
+        options.aspect_ratio = None;
+        options.width = Dimension::Px(800.0);
+        options.height = Dimension::Px(600.0);
+        let attrs = options.attributes();
+        assert!(attrs.container_style.contains("aspect-ratio: 800 / 600;"));
+        assert!(
+            attrs
+                .spacer_style
+                .as_deref()
+                .unwrap()
+                .contains("padding-top: 75%;")
+        );
+
+        options.layout = Layout::Responsive;
+        let attrs = options.attributes();
+        assert!(!attrs.container_style.contains("aspect-ratio"));
+        assert!(
+            attrs
+                .current_style(ImageState::Loading)
+                .contains("position: absolute;")
+        );
+    }
+
+    #[test]
+    fn picture_sources_follow_the_image() {
+        let breakpoints = Breakpoints::default();
+        let sources = [
+            Source::avif("/hero.avif"),
+            Source::new("/hero-square.jpg")
+                .with_media("(max-width: 640px)")
+                .with_sizes("50vw"),
+        ];
+        let options = ImageOptions {
+            width: Dimension::Px(800.0),
+            height: Dimension::Px(600.0),
+            sizes: "100vw",
+            sources: &sources,
+            layout: Layout::Responsive,
+            loading: Loading::Lazy,
+            decoding: Decoding::Deferred,
+            ..options(&breakpoints)
+        };
+        let attrs = options.attributes();
+        assert!(attrs.current_sources(ImageState::Idle, None).is_empty());
+        let rendered = attrs.current_sources(ImageState::Loading, None);
+        assert_eq!(
+            rendered[0].sizes, "100vw",
+            "inherits the sizes of the image"
+        );
+        assert_eq!(rendered[1].sizes, "50vw");
+        assert!(!attrs.is_deferred(), "the browser picks the source");
+        assert!(
+            attrs
+                .current_sources(ImageState::Fallback, Some("/fallback.jpg"))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn fallback_drops_srcset() {
+        let breakpoints = Breakpoints::default();
+        let loader =
+            Loader::new(|props: LoaderProps<'_>| format!("{}?w={}", props.src, props.width));
+        let options = ImageOptions {
+            width: Dimension::Px(800.0),
+            height: Dimension::Px(600.0),
+            layout: Layout::Responsive,
+            loading: Loading::Lazy,
+            loader: Some(&loader),
+            unoptimized: false,
+            ..options(&breakpoints)
+        };
+        let attrs = options.attributes();
+        assert_eq!(attrs.current_src(ImageState::Idle, None), None);
+        assert!(attrs.current_srcset(ImageState::Loading, None).is_some());
+        assert_eq!(
+            attrs.current_src(ImageState::Fallback, Some("/fallback.jpg")),
+            Some("/fallback.jpg".to_string())
+        );
+        assert_eq!(
+            attrs.current_srcset(ImageState::Fallback, Some("/fallback.jpg")),
+            None
+        );
+    }
+
+    #[test]
+    fn loaders_receive_the_height() {
+        let breakpoints = Breakpoints::default();
+        let loader = Loader::new(|props: LoaderProps<'_>| {
+            format!("{}?w={}&h={:?}", props.src, props.width, props.height)
+        });
+        let options = ImageOptions {
+            aspect_ratio: Some(AspectRatio::new(16.0, 9.0)),
+            layout: Layout::Responsive,
+            loader: Some(&loader),
+            unoptimized: false,
+            ..options(&breakpoints)
+        };
+        let attrs = options.attributes();
+        assert_eq!(attrs.src, "/hero.jpg?w=3840&h=Some(2160)");
+        assert!(attrs.srcset.starts_with("/hero.jpg?w=640&h=Some(360) 640w"));
+
+        let attrs = ImageOptions {
+            aspect_ratio: None,
+            ..options
+        }
+        .attributes();
+        assert_eq!(attrs.src, "/hero.jpg?w=3840&h=None");
+    }
+
+    #[test]
+    fn deferred_image_reveals_once_decoded() {
+        let breakpoints = Breakpoints::default();
+        let blur: Placeholder = Placeholder::Blur("data:image/png;base64,AAAA".into());
+        let options = ImageOptions {
+            width: Dimension::Px(800.0),
+            height: Dimension::Px(600.0),
+            srcset: "/hero.jpg 1x, /hero@2x.jpg 2x",
+            placeholder: &blur,
+            decoding: Decoding::Deferred,
+            ..options(&breakpoints)
+        };
+        let attrs = options.attributes();
+        assert!(attrs.awaits_decode(ImageState::Loading, None));
+        assert_eq!(attrs.current_src(ImageState::Loading, None), None);
+        assert_eq!(attrs.current_srcset(ImageState::Loading, None), None);
+        assert!(
+            attrs
+                .current_style(ImageState::Loading)
+                .contains("blur(20px)")
+        );
+
+        assert!(attrs.current_src(ImageState::Decoded, None).is_some());
+        assert!(attrs.current_srcset(ImageState::Decoded, None).is_some());
+        assert!(!attrs.current_style(ImageState::Decoded).contains("blur"));
+        assert!(
+            attrs
+                .current_src(ImageState::Fallback, Some("/fallback.jpg"))
+                .is_some(),
+            "fallbacks are not deferred"
+        );
+    }
+
+    #[test]
+    fn transitions_remove_the_placeholder() {
+        let breakpoints = Breakpoints::default();
+        let blur: Placeholder = Placeholder::Blur("data:image/png;base64,AAAA".into());
+        let options = ImageOptions {
+            width: Dimension::Px(800.0),
+            height: Dimension::Px(600.0),
+            placeholder: &blur,
+            transition: Transition::crossfade(),
+            ..options(&breakpoints)
+        };
+        let attrs = options.attributes();
+        assert!(
+            attrs
+                .current_style(ImageState::Loading)
+                .contains("opacity: 0;")
+        );
+        assert!(!attrs.current_style(ImageState::Loading).contains("blur"));
+        let layer = attrs.placeholder_style(ImageState::Loading, false).unwrap();
+        assert!(layer.contains("blur(20px)") && layer.contains("opacity: 1;"));
+
+        assert!(
+            attrs
+                .current_style(ImageState::Decoded)
+                .contains("opacity: 1;")
+        );
+        let layer = attrs.placeholder_style(ImageState::Decoded, false).unwrap();
+        assert!(layer.contains("opacity: 0;"), "fading out");
+        assert_eq!(attrs.placeholder_style(ImageState::Decoded, true), None);
+
+        let reduced = ImageOptions {
+            reduced_motion: true,
+            ..options
+        }
+        .attributes();
+        assert_eq!(reduced.transition, Transition::none());
+        assert_eq!(reduced.placeholder_style(ImageState::Loading, false), None);
+        assert!(
+            !reduced
+                .current_style(ImageState::Decoded)
+                .contains("transition")
+        );
+    }
+
+    #[test]
+    fn static_images_fill_unset_props() {
+        let breakpoints = Breakpoints::default();
+        let options = ImageOptions {
+            src: "",
+            static_image: Some(
+                StaticImage::new("/assets/hero.jpg", 1200, 800)
+                    .with_blur_data_url("data:image/webp;base64,AAAA"),
+            ),
+            layout: Layout::Responsive,
+            ..options(&breakpoints)
+        };
+        let attrs = options.attributes();
+        assert_eq!(attrs.src, "/assets/hero.jpg");
+        assert_eq!(
+            (attrs.width.as_str(), attrs.height.as_str()),
+            ("1200", "800")
+        );
+        assert!(
+            attrs
+                .spacer_style
+                .as_deref()
+                .unwrap()
+                .contains("padding-top: 66.66")
+        );
+        assert!(
+            attrs
+                .underlay_style
+                .unwrap()
+                .contains("data:image/webp;base64,AAAA")
+        );
+
+        let color: Placeholder = Placeholder::Color("red".into());
+        let attrs = ImageOptions {
+            src: "/hero@2x.jpg",
+            width: Dimension::Px(600.0),
+            height: Dimension::Px(400.0),
+            placeholder: &color,
+            ..options
+        }
+        .attributes();
+        assert_eq!(attrs.src, "/hero@2x.jpg");
+        assert_eq!(
+            (attrs.width.as_str(), attrs.height.as_str()),
+            ("600", "400")
+        );
+        assert_eq!(
+            attrs.underlay_style.as_deref(),
+            Some("background-color: red;")
+        );
+    }
+
+    #[test]
+    fn every_layout_handles_every_dimension() {
+        let breakpoints = Breakpoints::default();
+        let layouts = [
+            Layout::Fill,
+            Layout::Responsive,
+            Layout::Intrinsic,
+            Layout::Fixed,
+            Layout::Auto,
+            Layout::Stretch,
+            Layout::ScaleDown,
+            Layout::AspectRatio,
+        ];
+        let dimensions = [
+            Dimension::Auto,
+            Dimension::Intrinsic,
+            Dimension::Px(800.0),
+            Dimension::Px(0.0),
+            Dimension::Percent(50.0),
+            Dimension::Rem(20.0),
+            Dimension::Vw(100.0),
+        ];
+        for layout in layouts {
+            for width in dimensions {
+                for height in dimensions {
+                    let attrs = ImageOptions {
+                        width,
+                        height,
+                        layout,
+                        ..options(&breakpoints)
+                    }
+                    .attributes();
+                    assert_eq!(attrs.width, width.attribute());
+                    assert_eq!(
+                        attrs.spacer_style.is_some(),
+                        matches!(layout, Layout::Responsive | Layout::AspectRatio)
+                    );
+                }
+            }
+        }
+
+        let attrs = ImageOptions {
+            width: Dimension::Percent(50.0),
+            height: Dimension::Px(300.0),
+            ..options(&breakpoints)
+        }
+        .attributes();
+        assert_eq!((attrs.width.as_str(), attrs.height.as_str()), ("", "300"));
+        assert!(attrs.img_style.contains("width: 50%;"));
+        assert!(!attrs.img_style.contains("height"));
+    }
+}
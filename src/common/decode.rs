@@ -0,0 +1,40 @@
+use super::core::LoadEvent;
+use super::{CrossOrigin, ImageError, ReferrerPolicy};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::HtmlImageElement;
+
+/// Fetches and decodes an image off-DOM with `HTMLImageElement.decode()`.
+///
+/// `srcset` and `sizes` are set before `src` so the browser picks the same candidate as an
+/// `<img>` with the same attributes would, and shares its cache entry. Once the returned future
+/// resolves, the image can be painted without decoding on the main thread.
+///
+/// # Errors
+/// Returns [`ImageError::Decode`] when the image cannot be fetched or decoded.
+pub async fn decode_image(
+    src: &str,
+    srcset: &str,
+    sizes: &str,
+    crossorigin: &CrossOrigin,
+    referrerpolicy: ReferrerPolicy,
+) -> Result<LoadEvent, ImageError> {
+    let decode_error = || ImageError::Decode {
+        url: src.to_string(),
+    };
+    let img = HtmlImageElement::new().map_err(|_| decode_error())?;
+
+    img.set_cross_origin(crossorigin.as_str());
+    img.set_referrer_policy(referrerpolicy.as_str());
+    if !srcset.is_empty() {
+        img.set_srcset(srcset);
+        if !sizes.is_empty() {
+            img.set_sizes(sizes);
+        }
+    }
+    img.set_src(src);
+
+    JsFuture::from(img.decode())
+        .await
+        .map_err(|_| decode_error())?;
+    Ok(LoadEvent::from_element(&img, None))
+}
@@ -0,0 +1,214 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A `width` or `height` of an image.
+///
+/// Only pixel lengths become the `width` and `height` attributes of the `<img>` and reach the
+/// `loader`; the other lengths are applied as CSS. Strings are parsed leniently, so an empty
+/// or invalid value falls back to `Auto` instead of panicking.
+///
+/// # Example
+/// ```rust
+/// use image_rs::Dimension;
+///
+/// assert_eq!("800".parse(), Ok(Dimension::Px(800.0)));
+/// assert_eq!("50%".parse(), Ok(Dimension::Percent(50.0)));
+/// assert_eq!(Dimension::from(""), Dimension::Auto);
+/// assert_eq!(Dimension::from(640), Dimension::Px(640.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Dimension {
+    /// Not set (default): the size is left to the layout.
+    #[default]
+    Auto,
+
+    /// The intrinsic size of the image file, taken from the `static_image` when it is set.
+    Intrinsic,
+
+    /// A length in CSS pixels, e.g. `"800"` or `"800px"`.
+    Px(f64),
+
+    /// A percentage of the container, e.g. `"50%"`.
+    Percent(f64),
+
+    /// A multiple of the root font size, e.g. `"20rem"`.
+    Rem(f64),
+
+    /// A percentage of the viewport width, e.g. `"100vw"`.
+    Vw(f64),
+}
+
+impl Dimension {
+    /// Returns `true` if no length is set, i.e. `Auto` or `Intrinsic`.
+    pub fn is_auto(&self) -> bool {
+        matches!(self, Dimension::Auto | Dimension::Intrinsic)
+    }
+
+    /// Returns the length in whole pixels, `None` for other units and invalid lengths.
+    pub fn pixels(&self) -> Option<u32> {
+        match *self {
+            Dimension::Px(px) if is_valid(px) && px > 0.0 => Some(px.round() as u32),
+            _ => None,
+        }
+    }
+
+    /// Returns the `width` or `height` attribute of the `<img>`: the pixels, or an empty
+    /// string when the length is not in pixels.
+    pub fn attribute(&self) -> String {
+        self.pixels().map(|px| px.to_string()).unwrap_or_default()
+    }
+
+    /// Returns the CSS length, `None` for `Auto`, `Intrinsic` and invalid lengths.
+    pub fn as_css(&self) -> Option<String> {
+        let (value, unit) = match *self {
+            Dimension::Auto | Dimension::Intrinsic => return None,
+            Dimension::Px(value) => (value, "px"),
+            Dimension::Percent(value) => (value, "%"),
+            Dimension::Rem(value) => (value, "rem"),
+            Dimension::Vw(value) => (value, "vw"),
+        };
+        is_valid(value).then(|| format!("{value}{unit}"))
+    }
+
+    /// Returns the values of `width` and `height` when both are positive lengths of the same
+    /// unit.
+    ///
+    /// Percentages are relative to the width and the height of the container respectively,
+    /// so they never give a ratio.
+    pub(crate) fn lengths(width: Dimension, height: Dimension) -> Option<(f64, f64)> {
+        let (width, height) = match (width, height) {
+            (Dimension::Px(width), Dimension::Px(height))
+            | (Dimension::Rem(width), Dimension::Rem(height))
+            | (Dimension::Vw(width), Dimension::Vw(height)) => (width, height),
+            _ => return None,
+        };
+        (is_valid(width) && is_valid(height) && width > 0.0 && height > 0.0)
+            .then_some((width, height))
+    }
+}
+
+fn is_valid(value: f64) -> bool {
+    value.is_finite() && value >= 0.0
+}
+
+impl fmt::Display for Dimension {
+    /// Formats the dimension as parsed by [`FromStr`], e.g. `800px` or `auto`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dimension::Auto => f.write_str("auto"),
+            Dimension::Intrinsic => f.write_str("intrinsic"),
+            _ => f.write_str(&self.as_css().unwrap_or_else(|| "auto".into())),
+        }
+    }
+}
+
+impl FromStr for Dimension {
+    type Err = ();
+
+    /// Parses a number of pixels with an optional `px`, `%`, `rem` or `vw` unit, `auto`
+    /// (or an empty string) and `intrinsic`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_lowercase();
+        match s.as_str() {
+            "" | "auto" => return Ok(Dimension::Auto),
+            "intrinsic" => return Ok(Dimension::Intrinsic),
+            _ => {}
+        }
+        let (number, unit) = s
+            .find(|c: char| c.is_ascii_alphabetic() || c == '%')
+            .map_or((s.as_str(), ""), |index| s.split_at(index));
+        let value = number
+            .trim_end()
+            .parse::<f64>()
+            .ok()
+            .filter(|value| is_valid(*value))
+            .ok_or(())?;
+        match unit {
+            "" | "px" => Ok(Dimension::Px(value)),
+            "%" => Ok(Dimension::Percent(value)),
+            "rem" => Ok(Dimension::Rem(value)),
+            "vw" => Ok(Dimension::Vw(value)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<u32> for Dimension {
+    fn from(px: u32) -> Self {
+        Dimension::Px(f64::from(px))
+    }
+}
+
+impl From<&str> for Dimension {
+    /// Parses the dimension, falling back to `Auto` when it is invalid.
+    fn from(s: &str) -> Self {
+        s.parse().unwrap_or_default()
+    }
+}
+
+impl From<String> for Dimension {
+    fn from(s: String) -> Self {
+        Dimension::from(s.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_unit() {
+        assert_eq!("800".parse(), Ok(Dimension::Px(800.0)));
+        assert_eq!(" 12.5px ".parse(), Ok(Dimension::Px(12.5)));
+        assert_eq!("100%".parse(), Ok(Dimension::Percent(100.0)));
+        assert_eq!("2REM".parse(), Ok(Dimension::Rem(2.0)));
+        assert_eq!("100vw".parse(), Ok(Dimension::Vw(100.0)));
+        assert_eq!("".parse(), Ok(Dimension::Auto));
+        assert_eq!("auto".parse(), Ok(Dimension::Auto));
+        assert_eq!("intrinsic".parse(), Ok(Dimension::Intrinsic));
+        for invalid in ["-10", "NaN", "inf", "10em", "px"] {
+            assert_eq!(invalid.parse::<Dimension>(), Err(()), "{invalid}");
+            assert_eq!(Dimension::from(invalid), Dimension::Auto);
+        }
+    }
+
+    #[test]
+    fn only_pixels_become_attributes() {
+        assert_eq!(Dimension::from(800).attribute(), "800");
+        assert_eq!(Dimension::Px(0.0).attribute(), "");
+        assert_eq!(Dimension::Percent(50.0).attribute(), "");
+        assert_eq!(Dimension::Percent(50.0).as_css().as_deref(), Some("50%"));
+        assert_eq!(Dimension::Auto.as_css(), None);
+        assert_eq!(Dimension::Px(f64::NAN).as_css(), None);
+        assert_eq!(Dimension::Rem(1.5).to_string(), "1.5rem");
+    }
+
+    #[test]
+    fn lengths_need_the_same_unit() {
+        assert_eq!(
+            Dimension::lengths(Dimension::Px(800.0), Dimension::Px(600.0)),
+            Some((800.0, 600.0))
+        );
+        assert_eq!(
+            Dimension::lengths(Dimension::Vw(100.0), Dimension::Vw(50.0)),
+            Some((100.0, 50.0))
+        );
+        assert_eq!(
+            Dimension::lengths(Dimension::Px(800.0), Dimension::Percent(50.0)),
+            None
+        );
+        assert_eq!(
+            Dimension::lengths(Dimension::Px(0.0), Dimension::Px(600.0)),
+            None
+        );
+        assert_eq!(Dimension::lengths(Dimension::Auto, Dimension::Auto), None);
+    }
+
+    #[test]
+    fn percentages_have_no_lengths() {
+        assert_eq!(
+            Dimension::lengths(Dimension::Percent(100.0), Dimension::Percent(50.0)),
+            None
+        );
+    }
+}
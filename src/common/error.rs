@@ -0,0 +1,119 @@
+use std::fmt;
+
+/// Error reported through the `on_error` callback of an `Image`.
+///
+/// Every variant carries the URL that failed, so errors can be grouped by asset or by host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageError {
+    /// The image could not be decoded, either because the `<img>` fired its `error` event or
+    /// because the fetched bytes are not a supported image format.
+    Decode {
+        /// The URL of the image.
+        url: String,
+    },
+
+    /// The server answered with a non-successful status.
+    Http {
+        /// The URL of the image.
+        url: String,
+        /// The HTTP status code.
+        status: u16,
+        /// The response body, empty if it could not be read.
+        body: String,
+    },
+
+    /// The request failed before a response was received.
+    Network {
+        /// The URL of the image.
+        url: String,
+        /// The message of the underlying error.
+        message: String,
+    },
+
+    /// The fallback image was fetched but failed to load in the `<img>`.
+    Fallback {
+        /// The URL of the fallback image.
+        url: String,
+    },
+
+    /// The request did not complete in time.
+    Timeout {
+        /// The URL of the image.
+        url: String,
+        /// The time waited before giving up, in milliseconds.
+        after_ms: u32,
+    },
+
+    /// The request was cancelled, e.g. because the component unmounted.
+    Aborted {
+        /// The URL of the image.
+        url: String,
+    },
+}
+
+impl ImageError {
+    /// Returns the URL the error relates to.
+    pub fn url(&self) -> &str {
+        match self {
+            ImageError::Decode { url }
+            | ImageError::Http { url, .. }
+            | ImageError::Network { url, .. }
+            | ImageError::Fallback { url }
+            | ImageError::Timeout { url, .. }
+            | ImageError::Aborted { url } => url,
+        }
+    }
+
+    /// Returns `true` if trying the same URL again may succeed.
+    ///
+    /// Network errors, timeouts, `408`, `429` and `5xx` statuses are transient. A missing
+    /// asset or an invalid image will fail the same way on every attempt.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ImageError::Network { .. } | ImageError::Timeout { .. } => true,
+            ImageError::Http { status, .. } => matches!(status, 408 | 429 | 500..=599),
+            ImageError::Decode { .. }
+            | ImageError::Fallback { .. }
+            | ImageError::Aborted { .. } => false,
+        }
+    }
+
+    /// Returns a short, stable identifier of the variant, suitable as a metrics label.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ImageError::Decode { .. } => "decode",
+            ImageError::Http { .. } => "http",
+            ImageError::Network { .. } => "network",
+            ImageError::Fallback { .. } => "fallback",
+            ImageError::Timeout { .. } => "timeout",
+            ImageError::Aborted { .. } => "aborted",
+        }
+    }
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::Decode { url } => write!(f, "failed to load image {url}"),
+            ImageError::Http { url, status, body } if body.is_empty() => {
+                write!(f, "failed to fetch image {url}: status {status}")
+            }
+            ImageError::Http { url, status, body } => {
+                write!(
+                    f,
+                    "failed to fetch image {url}: status {status}, body {body:?}"
+                )
+            }
+            ImageError::Network { url, message } => {
+                write!(f, "network error while fetching image {url}: {message}")
+            }
+            ImageError::Fallback { url } => write!(f, "failed to load fallback image {url}"),
+            ImageError::Timeout { url, after_ms } => {
+                write!(f, "timed out after {after_ms}ms while fetching image {url}")
+            }
+            ImageError::Aborted { url } => write!(f, "aborted fetching image {url}"),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
@@ -0,0 +1,323 @@
+use super::ImageError;
+use super::sniff::{ImageInfo, sniff};
+use gloo_net::http::Request;
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::wasm_bindgen::prelude::*;
+use web_sys::{AbortController, AbortSignal, RequestCache};
+
+/// Upper bound of the delay between two attempts, in milliseconds.
+pub const MAX_BACKOFF_MS: u32 = 30_000;
+
+/// A fallback image, tried when the previous image of the chain fails.
+///
+/// # Example
+/// ```rust
+/// use image_rs::common::fallback::ImageSource;
+///
+/// let source = ImageSource::new("https://backup-cdn.example.com/hero.jpg")
+///     .with_retries(2)
+///     .with_backoff(500)
+///     .with_timeout(3_000);
+/// assert_eq!(source.retries, 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageSource {
+    /// The URL of the fallback image.
+    pub src: String,
+
+    /// How many times a failed attempt is retried before moving on to the next source.
+    pub retries: u32,
+
+    /// The delay before the first retry in milliseconds, doubled on every following retry.
+    pub backoff_ms: u32,
+
+    /// How long a single attempt may take in milliseconds, `0` to wait indefinitely.
+    pub timeout_ms: u32,
+}
+
+impl ImageSource {
+    /// Creates a source tried once, with a 10 second timeout.
+    pub fn new(src: impl Into<String>) -> Self {
+        ImageSource {
+            src: src.into(),
+            retries: 0,
+            backoff_ms: 250,
+            timeout_ms: 10_000,
+        }
+    }
+
+    /// Sets how many times a failed attempt is retried.
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Sets the delay before the first retry, in milliseconds.
+    pub fn with_backoff(mut self, backoff_ms: u32) -> Self {
+        self.backoff_ms = backoff_ms;
+        self
+    }
+
+    /// Sets the timeout of a single attempt, in milliseconds.
+    pub fn with_timeout(mut self, timeout_ms: u32) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+}
+
+/// A step of the fallback chain, reported through the `on_fallback` callback of an `Image`.
+///
+/// `source` is the index of the source in the chain, `fallback_src` first if set, then
+/// `fallbacks` in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FallbackEvent {
+    /// An attempt failed and the same source is retried after `delay_ms`.
+    Retry {
+        /// The index of the source.
+        source: usize,
+        /// The attempt that failed, starting at `0`.
+        attempt: u32,
+        /// The delay before the next attempt, in milliseconds.
+        delay_ms: u32,
+        /// Why the attempt failed.
+        error: ImageError,
+    },
+
+    /// A source failed for good and the chain moves on to the next one, if any.
+    Skipped {
+        /// The index of the source.
+        source: usize,
+        /// Why the source failed.
+        error: ImageError,
+    },
+
+    /// A source was validated and replaces the image.
+    Resolved {
+        /// The index of the source.
+        source: usize,
+        /// The URL of the source.
+        url: String,
+    },
+}
+
+/// Builds the fallback chain of an image: `fallback_src` if set, then `fallbacks`.
+pub fn fallback_chain(fallback_src: &str, fallbacks: &[ImageSource]) -> Vec<ImageSource> {
+    let mut chain = Vec::with_capacity(fallbacks.len() + 1);
+    if !fallback_src.is_empty() {
+        chain.push(ImageSource::new(fallback_src));
+    }
+    chain.extend(fallbacks.iter().cloned());
+    chain
+}
+
+/// Returns the delay before retrying after `attempt` failed, in milliseconds.
+///
+/// The delay doubles on every attempt up to [`MAX_BACKOFF_MS`]. `jitter`, in `[0, 1)`, then
+/// scales it between 50% and 100% so that many images failing at once do not retry in lockstep.
+pub fn backoff_delay(backoff_ms: u32, attempt: u32, jitter: f64) -> u32 {
+    let delay = backoff_ms
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_BACKOFF_MS);
+    let jitter = jitter.clamp(0.0, 1.0);
+    (f64::from(delay) * (0.5 + jitter * 0.5)).round() as u32
+}
+
+/// Checks that the fallback image at `url` can be fetched before swapping it in.
+///
+/// The request bypasses the HTTP cache so a fallback that recovered since the last attempt is
+/// picked up. The response must be successful (2xx) and its body must be a supported image,
+/// as detected by [`sniff`] from the `Content-Type` header and the magic bytes.
+///
+/// # Errors
+/// Returns [`ImageError::Http`] with the status and body of an unsuccessful response,
+/// [`ImageError::Network`] when no response was received, or [`ImageError::Decode`] when the
+/// body is not an image.
+pub async fn check_fallback(url: &str) -> Result<ImageInfo, ImageError> {
+    fetch_image(url, None).await
+}
+
+async fn fetch_image(url: &str, signal: Option<&AbortSignal>) -> Result<ImageInfo, ImageError> {
+    let network_error = |err: gloo_net::Error| ImageError::Network {
+        url: url.to_string(),
+        message: err.to_string(),
+    };
+
+    let response = Request::get(url)
+        .cache(RequestCache::Reload)
+        .abort_signal(signal)
+        .send()
+        .await
+        .map_err(network_error)?;
+
+    if !response.ok() {
+        return Err(ImageError::Http {
+            url: url.to_string(),
+            status: response.status(),
+            body: response.text().await.unwrap_or_default(),
+        });
+    }
+
+    let content_type = response.headers().get("content-type");
+    let bytes = response.binary().await.map_err(network_error)?;
+
+    sniff(content_type.as_deref(), &bytes).ok_or_else(|| ImageError::Decode {
+        url: url.to_string(),
+    })
+}
+
+/// Checks a single attempt of `source`, aborting it after `source.timeout_ms`.
+async fn check_source(source: &ImageSource) -> Result<ImageInfo, ImageError> {
+    let window = web_sys::window();
+    let controller = AbortController::new().ok();
+    let (Some(window), Some(controller), true) = (window, controller, source.timeout_ms > 0) else {
+        return check_fallback(&source.src).await;
+    };
+
+    let timed_out = Rc::new(Cell::new(false));
+    let timer = {
+        let timed_out = timed_out.clone();
+        let controller = controller.clone();
+        Closure::once(move || {
+            timed_out.set(true);
+            controller.abort();
+        })
+    };
+    let handle = window
+        .set_timeout_with_callback_and_timeout_and_arguments_0(
+            timer.as_ref().unchecked_ref(),
+            i32::try_from(source.timeout_ms).unwrap_or(i32::MAX),
+        )
+        .ok();
+
+    let result = fetch_image(&source.src, Some(&controller.signal())).await;
+    if let Some(handle) = handle {
+        window.clear_timeout_with_handle(handle);
+    }
+
+    match result {
+        Err(ImageError::Network { url, .. }) if timed_out.get() => Err(ImageError::Timeout {
+            url,
+            after_ms: source.timeout_ms,
+        }),
+        result => result,
+    }
+}
+
+/// Waits for `ms` milliseconds.
+async fn sleep(ms: u32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        let scheduled = web_sys::window().and_then(|window| {
+            window
+                .set_timeout_with_callback_and_timeout_and_arguments_0(
+                    &resolve,
+                    i32::try_from(ms).unwrap_or(i32::MAX),
+                )
+                .ok()
+        });
+        if scheduled.is_none() {
+            let _ = resolve.call0(&JsValue::NULL);
+        }
+    });
+    let _ = JsFuture::from(promise).await;
+}
+
+/// Tries the sources of `chain` in order, starting at `start`, until one can be displayed.
+///
+/// Each source is attempted `1 + retries` times. Retries are spaced with [`backoff_delay`] and
+/// only happen for errors worth retrying (see [`ImageError::is_retryable`]): a missing asset
+/// moves on to the next source right away. Every step is reported to `on_step`.
+///
+/// `is_cancelled` is checked after every attempt and backoff, e.g. to stop once the `src` of
+/// the image changed.
+///
+/// # Errors
+/// Returns [`ImageError::Aborted`] once `is_cancelled` returns `true`, otherwise the error of
+/// the last source when every source failed.
+pub async fn resolve_fallback(
+    chain: &[ImageSource],
+    start: usize,
+    is_cancelled: impl Fn() -> bool,
+    on_step: impl Fn(FallbackEvent),
+) -> Result<(usize, ImageInfo), ImageError> {
+    let mut last_error = None;
+
+    for (index, source) in chain.iter().enumerate().skip(start) {
+        let aborted = || ImageError::Aborted {
+            url: source.src.clone(),
+        };
+        let mut attempt = 0;
+        let error = loop {
+            let result = check_source(source).await;
+            if is_cancelled() {
+                return Err(aborted());
+            }
+            match result {
+                Ok(info) => {
+                    on_step(FallbackEvent::Resolved {
+                        source: index,
+                        url: source.src.clone(),
+                    });
+                    return Ok((index, info));
+                }
+                Err(error) if attempt < source.retries && error.is_retryable() => {
+                    let delay_ms =
+                        backoff_delay(source.backoff_ms, attempt, js_sys::Math::random());
+                    on_step(FallbackEvent::Retry {
+                        source: index,
+                        attempt,
+                        delay_ms,
+                        error,
+                    });
+                    sleep(delay_ms).await;
+                    if is_cancelled() {
+                        return Err(aborted());
+                    }
+                    attempt += 1;
+                }
+                Err(error) => break error,
+            }
+        };
+        on_step(FallbackEvent::Skipped {
+            source: index,
+            error: error.clone(),
+        });
+        last_error = Some(error);
+    }
+
+    Err(last_error.unwrap_or_else(|| ImageError::Fallback { url: String::new() }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_and_is_capped() {
+        assert_eq!(backoff_delay(250, 0, 1.0), 250);
+        assert_eq!(backoff_delay(250, 1, 1.0), 500);
+        assert_eq!(backoff_delay(250, 3, 1.0), 2_000);
+        assert_eq!(backoff_delay(250, 40, 1.0), MAX_BACKOFF_MS);
+        assert_eq!(backoff_delay(u32::MAX, 2, 1.0), MAX_BACKOFF_MS);
+    }
+
+    #[test]
+    fn jitter_keeps_at_least_half_the_delay() {
+        assert_eq!(backoff_delay(1_000, 0, 0.0), 500);
+        assert_eq!(backoff_delay(1_000, 0, 0.5), 750);
+        assert_eq!(backoff_delay(1_000, 0, 7.0), 1_000);
+    }
+
+    #[test]
+    fn chain_starts_with_fallback_src() {
+        let fallbacks = [ImageSource::new("/b.jpg").with_retries(3)];
+        let chain = fallback_chain("/a.jpg", &fallbacks);
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain[0], ImageSource::new("/a.jpg"));
+        assert_eq!(chain[1].retries, 3);
+        assert_eq!(fallback_chain("", &fallbacks).len(), 1);
+    }
+}
@@ -0,0 +1,144 @@
+use web_sys::Element;
+
+/// Share of an image that must intersect for it to be considered visible.
+pub const DEFAULT_THRESHOLD: f64 = 0.1;
+
+/// The scroll container an image is observed against.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ScrollRoot {
+    /// The browser viewport (default).
+    #[default]
+    Viewport,
+
+    /// The closest ancestor of the image matching the CSS selector, or the first element of the
+    /// document matching it. Falls back to the viewport when no element matches.
+    Selector(String),
+
+    /// A specific element, e.g. the node of a carousel or a modal.
+    Element(Element),
+}
+
+impl ScrollRoot {
+    /// Returns the element to observe `image` against, `None` for the viewport.
+    pub fn resolve(&self, image: &Element) -> Option<Element> {
+        match self {
+            ScrollRoot::Viewport => None,
+            ScrollRoot::Selector(selector) => {
+                image.closest(selector).ok().flatten().or_else(|| {
+                    web_sys::window()?
+                        .document()?
+                        .query_selector(selector)
+                        .ok()
+                        .flatten()
+                })
+            }
+            ScrollRoot::Element(element) => Some(element.clone()),
+        }
+    }
+}
+
+/// When a lazy image starts loading, mirroring the options of an `IntersectionObserver`.
+///
+/// # Example
+/// ```rust
+/// use image_rs::common::lazy::{LazyConfig, ScrollRoot};
+///
+/// // Images of a horizontal carousel start loading one slide ahead.
+/// let config = LazyConfig::default()
+///     .with_root(ScrollRoot::Selector(".carousel".into()))
+///     .with_root_margin("0px 100% 0px 100%")
+///     .with_thresholds([0.0]);
+/// assert_eq!(config.thresholds, vec![0.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LazyConfig {
+    /// The scroll container the image is observed against.
+    pub root: ScrollRoot,
+
+    /// Grows or shrinks the root before computing intersections, with the syntax of the CSS
+    /// `margin` property (e.g. "200px" or "0px 50% 0px 50%").
+    pub root_margin: String,
+
+    /// Shares of the image, from `0.0` to `1.0`, at which intersections are reported. The image
+    /// is considered visible once the smallest one is reached.
+    pub thresholds: Vec<f64>,
+}
+
+impl Default for LazyConfig {
+    fn default() -> Self {
+        LazyConfig::from_boundary("100px")
+    }
+}
+
+impl LazyConfig {
+    /// Creates the configuration matching the `lazy_boundary` prop: the viewport grown by
+    /// `lazy_boundary`, with the default threshold.
+    pub fn from_boundary(lazy_boundary: &str) -> Self {
+        LazyConfig {
+            root: ScrollRoot::Viewport,
+            root_margin: lazy_boundary.to_string(),
+            thresholds: vec![DEFAULT_THRESHOLD],
+        }
+    }
+
+    /// Sets the scroll container.
+    pub fn with_root(mut self, root: ScrollRoot) -> Self {
+        self.root = root;
+        self
+    }
+
+    /// Sets the margin around the root.
+    pub fn with_root_margin(mut self, root_margin: impl Into<String>) -> Self {
+        self.root_margin = root_margin.into();
+        self
+    }
+
+    /// Sets the thresholds, clamped to `[0, 1]`, sorted and deduplicated. An empty list keeps
+    /// the default threshold.
+    pub fn with_thresholds(mut self, thresholds: impl IntoIterator<Item = f64>) -> Self {
+        let mut thresholds: Vec<f64> = thresholds
+            .into_iter()
+            .filter(|threshold| !threshold.is_nan())
+            .map(|threshold| threshold.clamp(0.0, 1.0))
+            .collect();
+        thresholds.sort_by(f64::total_cmp);
+        thresholds.dedup();
+        if !thresholds.is_empty() {
+            self.thresholds = thresholds;
+        }
+        self
+    }
+
+    /// Returns the smallest threshold, reached when the image is considered visible.
+    pub fn min_threshold(&self) -> f64 {
+        self.thresholds
+            .iter()
+            .copied()
+            .reduce(f64::min)
+            .unwrap_or(DEFAULT_THRESHOLD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_matches_the_lazy_boundary() {
+        let config = LazyConfig::default();
+        assert_eq!(config.root, ScrollRoot::Viewport);
+        assert_eq!(config.root_margin, "100px");
+        assert_eq!(config.thresholds, vec![DEFAULT_THRESHOLD]);
+        assert_eq!(LazyConfig::from_boundary("300px").root_margin, "300px");
+    }
+
+    #[test]
+    fn thresholds_are_normalized() {
+        let config = LazyConfig::default().with_thresholds([0.5, 2.0, f64::NAN, -1.0, 0.5]);
+        assert_eq!(config.thresholds, vec![0.0, 0.5, 1.0]);
+        assert_eq!(config.min_threshold(), 0.0);
+
+        let config = LazyConfig::default().with_thresholds([]);
+        assert_eq!(config.thresholds, vec![DEFAULT_THRESHOLD]);
+    }
+}
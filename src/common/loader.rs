@@ -0,0 +1,232 @@
+use super::{ObjectFit, Position};
+use std::fmt;
+use std::sync::Arc;
+
+/// Width handed to a loader when the component does not know how wide the image will render.
+///
+/// Matches the largest default device width used by Next.js.
+pub const DEFAULT_LOADER_WIDTH: u32 = 3840;
+
+/// Parameters passed to an [`ImageLoader`] when building an image URL.
+///
+/// Mirrors the object Next.js hands to its custom loaders, extended with the `object-fit`
+/// and `object-position` of the image so loaders can pick a matching crop and gravity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LoaderProps<'a> {
+    /// The original `src` passed to the `Image` component.
+    pub src: &'a str,
+
+    /// The width, in pixels, the generated URL should be resized to.
+    pub width: u32,
+
+    /// The height, in pixels, matching `width` at the aspect ratio of the image, if known.
+    pub height: Option<u32>,
+
+    /// The requested quality, from 1 to 100, if any.
+    pub quality: Option<u8>,
+
+    /// How the image is resized to fit its container.
+    pub object_fit: ObjectFit,
+
+    /// How the image is positioned within its container.
+    pub object_position: Position,
+}
+
+impl LoaderProps<'_> {
+    /// Returns these parameters resized to `width`, scaling `height` to keep the aspect ratio.
+    pub fn with_width(self, width: u32) -> Self {
+        let height = match self.height {
+            Some(height) if self.width > 0 => {
+                Some((f64::from(height) * f64::from(width) / f64::from(self.width)).round() as u32)
+            }
+            _ => None,
+        };
+        LoaderProps {
+            width,
+            height: height.filter(|height| *height > 0),
+            ..self
+        }
+    }
+
+    /// Returns the height to crop or stretch the image to.
+    ///
+    /// Only `Cover` and `Fill` need one: with a single side, image services keep the aspect
+    /// ratio of the source, so crop and gravity parameters would have no effect.
+    pub fn crop_height(&self) -> Option<u32> {
+        match self.object_fit {
+            ObjectFit::Cover | ObjectFit::Fill => self.height,
+            ObjectFit::Contain | ObjectFit::ScaleDown | ObjectFit::None => None,
+        }
+    }
+}
+
+/// Turns an image source, a target width and a quality into a URL.
+///
+/// Implement this trait to plug an image CDN or resizing service into the `Image` component.
+/// Closures of the form `Fn(LoaderProps) -> String` implement it as well.
+///
+/// # Example
+/// ```rust
+/// use image_rs::common::{ImageLoader, LoaderProps};
+///
+/// struct MyCdn;
+///
+/// impl ImageLoader for MyCdn {
+///     fn url(&self, props: LoaderProps<'_>) -> String {
+///         format!("https://cdn.example.com{}?w={}", props.src, props.width)
+///     }
+/// }
+///
+/// let props = LoaderProps { src: "/hero.jpg", width: 640, ..Default::default() };
+/// assert_eq!(MyCdn.url(props), "https://cdn.example.com/hero.jpg?w=640");
+/// ```
+pub trait ImageLoader {
+    /// Returns the URL to fetch for the given loader parameters.
+    fn url(&self, props: LoaderProps<'_>) -> String;
+}
+
+impl<F> ImageLoader for F
+where
+    F: Fn(LoaderProps<'_>) -> String,
+{
+    fn url(&self, props: LoaderProps<'_>) -> String {
+        self(props)
+    }
+}
+
+/// A shareable handle to an [`ImageLoader`], used as the `loader` prop of the `Image` component.
+///
+/// Two `Loader`s compare equal only when they point to the same loader instance, which keeps
+/// component props cheap to diff.
+#[derive(Clone)]
+pub struct Loader(Arc<dyn ImageLoader + Send + Sync>);
+
+impl Loader {
+    /// Wraps an [`ImageLoader`] so it can be passed to the `Image` component.
+    pub fn new(loader: impl ImageLoader + Send + Sync + 'static) -> Self {
+        Loader(Arc::new(loader))
+    }
+
+    /// Returns the URL produced by the wrapped loader.
+    pub fn url(&self, props: LoaderProps<'_>) -> String {
+        self.0.url(props)
+    }
+}
+
+impl PartialEq for Loader {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for Loader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Loader").finish()
+    }
+}
+
+/// Parses the `quality` prop into a value between 1 and 100.
+///
+/// Accepts numeric strings (`"75"`) as well as the `"low"`, `"medium"` and `"high"` hints.
+/// Returns `None` for empty or unrecognized values so the loader can apply its own default.
+pub fn parse_quality(quality: &str) -> Option<u8> {
+    match quality.trim().to_lowercase().as_str() {
+        "" => None,
+        "low" => Some(50),
+        "medium" => Some(75),
+        "high" => Some(90),
+        other => other
+            .parse::<f64>()
+            .ok()
+            .filter(|q| q.is_finite())
+            .map(|q| q.round().clamp(1.0, 100.0) as u8),
+    }
+}
+
+/// Returns `true` when `src` must be used verbatim instead of being passed to a loader.
+///
+/// Inline `data:` and `blob:` URLs cannot be resized by a remote service.
+pub fn is_unoptimizable(src: &str) -> bool {
+    src.is_empty() || src.starts_with("data:") || src.starts_with("blob:")
+}
+
+/// Resolves the final `src` of an image.
+///
+/// The loader is skipped when `unoptimized` is `true`, when no loader is configured or
+/// when the source cannot be optimized (see [`is_unoptimizable`]).
+pub fn resolve_src(props: LoaderProps<'_>, loader: Option<&Loader>, unoptimized: bool) -> String {
+    match loader {
+        Some(loader) if !unoptimized && !is_unoptimizable(props.src) => loader.url(props),
+        _ => props.src.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cdn() -> Loader {
+        Loader::new(|props: LoaderProps<'_>| {
+            format!("https://cdn.example.com{}?w={}", props.src, props.width)
+        })
+    }
+
+    #[test]
+    fn quality_is_clamped_or_ignored() {
+        assert_eq!(parse_quality(" 75 "), Some(75));
+        assert_eq!(parse_quality("High"), Some(90));
+        assert_eq!(parse_quality("0"), Some(1));
+        assert_eq!(parse_quality("250"), Some(100));
+        assert_eq!(parse_quality("-5"), Some(1));
+        for ignored in ["", "best", "NaN", "inf"] {
+            assert_eq!(parse_quality(ignored), None, "{ignored}");
+        }
+    }
+
+    #[test]
+    fn loader_is_skipped_when_unoptimized() {
+        let loader = cdn();
+        let props = LoaderProps {
+            src: "/hero.jpg",
+            width: 640,
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_src(props, Some(&loader), false),
+            "https://cdn.example.com/hero.jpg?w=640"
+        );
+        assert_eq!(resolve_src(props, Some(&loader), true), "/hero.jpg");
+        assert_eq!(resolve_src(props, None, false), "/hero.jpg");
+
+        let inline = LoaderProps {
+            src: "data:image/png;base64,AAAA",
+            ..props
+        };
+        assert_eq!(
+            resolve_src(inline, Some(&loader), false),
+            "data:image/png;base64,AAAA"
+        );
+    }
+
+    #[test]
+    fn height_follows_the_width() {
+        let props = LoaderProps {
+            src: "/hero.jpg",
+            width: 1600,
+            height: Some(900),
+            object_fit: ObjectFit::Cover,
+            ..Default::default()
+        };
+        assert_eq!(props.with_width(640).height, Some(360));
+        assert_eq!(props.with_width(640).crop_height(), Some(360));
+
+        let contain = LoaderProps {
+            object_fit: ObjectFit::Contain,
+            ..props
+        };
+        assert_eq!(contain.crop_height(), None);
+
+        let unknown = LoaderProps { width: 0, ..props };
+        assert_eq!(unknown.with_width(640).height, None);
+    }
+}
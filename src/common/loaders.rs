@@ -0,0 +1,79 @@
+//! Ready-made [`ImageLoader`](super::ImageLoader) implementations for popular image CDNs.
+//!
+//! Each loader lives behind its own cargo feature:
+//!
+//! | Loader         | Feature      |
+//! | -------------- | ------------ |
+//! | [`Cloudinary`] | `cloudinary` |
+//! | [`Imgix`]      | `imgix`      |
+//! | [`Imgproxy`]   | `imgproxy`   |
+//! | [`Thumbor`]    | `thumbor`    |
+//! | [`Cloudflare`] | `cloudflare` |
+//!
+//! Wrap any of them in a [`Loader`](super::Loader) to hand it to the `Image` component.
+
+#[cfg(feature = "cloudflare")]
+mod cloudflare;
+#[cfg(feature = "cloudinary")]
+mod cloudinary;
+#[cfg(feature = "imgix")]
+mod imgix;
+#[cfg(feature = "imgproxy")]
+mod imgproxy;
+#[cfg(feature = "thumbor")]
+mod thumbor;
+
+#[cfg(feature = "cloudflare")]
+pub use cloudflare::Cloudflare;
+#[cfg(feature = "cloudinary")]
+pub use cloudinary::Cloudinary;
+#[cfg(feature = "imgix")]
+pub use imgix::Imgix;
+#[cfg(feature = "imgproxy")]
+pub use imgproxy::Imgproxy;
+#[cfg(feature = "thumbor")]
+pub use thumbor::Thumbor;
+
+/// Returns `true` if `src` points to another origin rather than a path on the CDN.
+fn is_absolute(src: &str) -> bool {
+    src.starts_with("http://") || src.starts_with("https://") || src.starts_with("//")
+}
+
+/// Strips the leading slash of a path so it can be appended to a base URL.
+fn normalize_src(src: &str) -> &str {
+    src.strip_prefix('/').unwrap_or(src)
+}
+
+/// Strips the trailing slash of a base URL.
+fn trim_base(base: &str) -> &str {
+    base.strip_suffix('/').unwrap_or(base)
+}
+
+/// Percent-encodes `value` the way `encodeURIComponent` does.
+#[cfg(any(
+    feature = "cloudinary",
+    feature = "imgix",
+    feature = "imgproxy",
+    feature = "thumbor"
+))]
+fn encode_uri_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'!'
+            | b'~'
+            | b'*'
+            | b'\''
+            | b'('
+            | b')' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
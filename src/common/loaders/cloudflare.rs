@@ -0,0 +1,148 @@
+use super::{is_absolute, normalize_src, trim_base};
+use crate::common::{ImageLoader, LoaderProps, ObjectFit, Position};
+
+/// Loader for [Cloudflare Images](https://developers.cloudflare.com/images/transform-images/transform-via-url/)
+/// transformations served from your zone.
+///
+/// # Example
+/// ```rust
+/// use image_rs::common::loaders::Cloudflare;
+/// use image_rs::common::{ImageLoader, LoaderProps};
+///
+/// let loader = Cloudflare::new("https://example.com");
+/// let props = LoaderProps { src: "/hero.jpg", width: 640, ..Default::default() };
+/// assert_eq!(
+///     loader.url(props),
+///     "https://example.com/cdn-cgi/image/width=640,fit=contain,format=auto/hero.jpg"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cloudflare {
+    zone: String,
+}
+
+impl Cloudflare {
+    /// Creates a loader for the Cloudflare zone at `zone`, e.g. `https://example.com`.
+    ///
+    /// Pass an empty string to generate URLs relative to the current origin.
+    pub fn new(zone: impl Into<String>) -> Self {
+        Cloudflare { zone: zone.into() }
+    }
+}
+
+/// Maps `object-fit` onto Cloudflare's `fit` option.
+fn fit(object_fit: ObjectFit) -> &'static str {
+    match object_fit {
+        ObjectFit::Fill => "squeeze",
+        ObjectFit::Contain => "contain",
+        ObjectFit::Cover => "cover",
+        ObjectFit::ScaleDown | ObjectFit::None => "scale-down",
+    }
+}
+
+/// Maps `object-position` onto Cloudflare's `gravity` option, `None` meaning centered.
+fn gravity(position: Position) -> Option<&'static str> {
+    match position {
+        Position::Center => None,
+        Position::Top => Some("top"),
+        Position::Bottom => Some("bottom"),
+        Position::Left => Some("left"),
+        Position::Right => Some("right"),
+        Position::TopLeft => Some("0x0"),
+        Position::TopRight => Some("1x0"),
+        Position::BottomLeft => Some("0x1"),
+        Position::BottomRight => Some("1x1"),
+    }
+}
+
+impl ImageLoader for Cloudflare {
+    fn url(&self, props: LoaderProps<'_>) -> String {
+        let height = props.crop_height();
+        let mut options = vec![format!("width={}", props.width)];
+        options.extend(height.map(|height| format!("height={height}")));
+        if let Some(quality) = props.quality {
+            options.push(format!("quality={quality}"));
+        }
+        match (props.object_fit, height) {
+            // Without a height, the aspect ratio is kept and there is nothing to crop.
+            (ObjectFit::Cover | ObjectFit::Fill, None) => {}
+            (ObjectFit::Cover, Some(_)) => {
+                options.push(format!("fit={}", fit(props.object_fit)));
+                options.extend(
+                    gravity(props.object_position).map(|gravity| format!("gravity={gravity}")),
+                );
+            }
+            (object_fit, _) => options.push(format!("fit={}", fit(object_fit))),
+        }
+        options.push("format=auto".to_string());
+
+        // Cloudflare expects absolute source URLs verbatim after the options.
+        let src = if is_absolute(props.src) {
+            props.src
+        } else {
+            normalize_src(props.src)
+        };
+
+        format!(
+            "{}/cdn-cgi/image/{}/{}",
+            trim_base(&self.zone),
+            options.join(","),
+            src
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_fit_and_position_to_fit_and_gravity() {
+        let loader = Cloudflare::new("https://example.com/");
+        let props = LoaderProps {
+            src: "https://origin.example.com/hero.jpg",
+            width: 1920,
+            height: Some(1080),
+            quality: Some(70),
+            object_fit: ObjectFit::Cover,
+            object_position: Position::TopLeft,
+        };
+        assert_eq!(
+            loader.url(props),
+            "https://example.com/cdn-cgi/image/width=1920,height=1080,quality=70,fit=cover,gravity=0x0,format=auto/https://origin.example.com/hero.jpg"
+        );
+
+        let fill = LoaderProps {
+            object_fit: ObjectFit::Fill,
+            ..props
+        };
+        assert_eq!(
+            loader.url(fill),
+            "https://example.com/cdn-cgi/image/width=1920,height=1080,quality=70,fit=squeeze,format=auto/https://origin.example.com/hero.jpg"
+        );
+
+        let unknown = LoaderProps {
+            height: None,
+            ..props
+        };
+        assert_eq!(
+            loader.url(unknown),
+            "https://example.com/cdn-cgi/image/width=1920,quality=70,format=auto/https://origin.example.com/hero.jpg"
+        );
+    }
+
+    #[test]
+    fn supports_relative_zone() {
+        let loader = Cloudflare::new("");
+        let props = LoaderProps {
+            src: "/hero.jpg",
+            width: 256,
+            object_fit: ObjectFit::ScaleDown,
+            ..Default::default()
+        };
+        assert_eq!(
+            loader.url(props),
+            "/cdn-cgi/image/width=256,fit=scale-down,format=auto/hero.jpg"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use super::{encode_uri_component, is_absolute, normalize_src, trim_base};
+use crate::common::{ImageLoader, LoaderProps, ObjectFit, Position};
+
+/// Loader for [Cloudinary](https://cloudinary.com/documentation/image_transformations).
+///
+/// Relative sources are served from the `upload` delivery type, absolute URLs are proxied
+/// through the `fetch` delivery type.
+///
+/// # Example
+/// ```rust
+/// use image_rs::common::loaders::Cloudinary;
+/// use image_rs::common::{ImageLoader, LoaderProps};
+///
+/// let loader = Cloudinary::new("demo");
+/// let props = LoaderProps { src: "/sample.jpg", width: 640, ..Default::default() };
+/// assert_eq!(
+///     loader.url(props),
+///     "https://res.cloudinary.com/demo/image/upload/f_auto,c_fit,w_640,q_auto/sample.jpg"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cloudinary {
+    cloud_name: String,
+    base_url: String,
+}
+
+impl Cloudinary {
+    /// Creates a loader for the given Cloudinary cloud name.
+    pub fn new(cloud_name: impl Into<String>) -> Self {
+        Cloudinary {
+            cloud_name: cloud_name.into(),
+            base_url: "https://res.cloudinary.com".to_string(),
+        }
+    }
+
+    /// Serves images from a custom domain (CNAME) instead of `res.cloudinary.com`.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+/// Maps `object-fit` onto Cloudinary's `c_` crop mode.
+fn crop(object_fit: ObjectFit) -> &'static str {
+    match object_fit {
+        ObjectFit::Fill => "scale",
+        ObjectFit::Contain => "fit",
+        ObjectFit::Cover => "fill",
+        ObjectFit::ScaleDown | ObjectFit::None => "limit",
+    }
+}
+
+/// Maps `object-position` onto Cloudinary's `g_` gravity.
+fn gravity(position: Position) -> &'static str {
+    match position {
+        Position::Center => "center",
+        Position::Top => "north",
+        Position::Bottom => "south",
+        Position::Left => "west",
+        Position::Right => "east",
+        Position::TopLeft => "north_west",
+        Position::TopRight => "north_east",
+        Position::BottomLeft => "south_west",
+        Position::BottomRight => "south_east",
+    }
+}
+
+impl ImageLoader for Cloudinary {
+    fn url(&self, props: LoaderProps<'_>) -> String {
+        let height = props.crop_height();
+        let mut params = vec!["f_auto".to_string()];
+        match (props.object_fit, height) {
+            // Without a height, the aspect ratio is kept and there is nothing to crop.
+            (ObjectFit::Cover | ObjectFit::Fill, None) => {}
+            (ObjectFit::Cover, Some(_)) => {
+                params.push(format!("c_{}", crop(props.object_fit)));
+                params.push(format!("g_{}", gravity(props.object_position)));
+            }
+            (object_fit, _) => params.push(format!("c_{}", crop(object_fit))),
+        }
+        params.push(format!("w_{}", props.width));
+        params.extend(height.map(|height| format!("h_{height}")));
+        params.push(match props.quality {
+            Some(quality) => format!("q_{quality}"),
+            None => "q_auto".to_string(),
+        });
+
+        let (delivery, src) = if is_absolute(props.src) {
+            ("fetch", encode_uri_component(props.src))
+        } else {
+            ("upload", normalize_src(props.src).to_string())
+        };
+
+        format!(
+            "{}/{}/image/{}/{}/{}",
+            trim_base(&self.base_url),
+            self.cloud_name,
+            delivery,
+            params.join(","),
+            src
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_fit_and_position_to_crop_and_gravity() {
+        let loader = Cloudinary::new("demo");
+        let props = LoaderProps {
+            src: "/hero.png",
+            width: 1080,
+            height: Some(720),
+            quality: Some(80),
+            object_fit: ObjectFit::Cover,
+            object_position: Position::TopRight,
+        };
+        assert_eq!(
+            loader.url(props),
+            "https://res.cloudinary.com/demo/image/upload/f_auto,c_fill,g_north_east,w_1080,h_720,q_80/hero.png"
+        );
+
+        let fill = LoaderProps {
+            object_fit: ObjectFit::Fill,
+            ..props
+        };
+        assert_eq!(
+            loader.url(fill),
+            "https://res.cloudinary.com/demo/image/upload/f_auto,c_scale,w_1080,h_720,q_80/hero.png"
+        );
+
+        let unknown = LoaderProps {
+            height: None,
+            ..props
+        };
+        assert_eq!(
+            loader.url(unknown),
+            "https://res.cloudinary.com/demo/image/upload/f_auto,w_1080,q_80/hero.png"
+        );
+    }
+
+    #[test]
+    fn fetches_absolute_urls() {
+        let loader = Cloudinary::new("demo").with_base_url("https://img.example.com/");
+        let props = LoaderProps {
+            src: "https://example.com/a b.jpg?v=1",
+            width: 640,
+            ..Default::default()
+        };
+        assert_eq!(
+            loader.url(props),
+            "https://img.example.com/demo/image/fetch/f_auto,c_fit,w_640,q_auto/https%3A%2F%2Fexample.com%2Fa%20b.jpg%3Fv%3D1"
+        );
+    }
+}
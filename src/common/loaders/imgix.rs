@@ -0,0 +1,142 @@
+use super::{encode_uri_component, is_absolute, normalize_src, trim_base};
+use crate::common::{ImageLoader, LoaderProps, ObjectFit, Position};
+
+/// Loader for [imgix](https://docs.imgix.com/apis/rendering).
+///
+/// Relative sources are resolved against the imgix source domain, absolute URLs are
+/// encoded into the path as expected by imgix Web Proxy sources.
+///
+/// # Example
+/// ```rust
+/// use image_rs::common::loaders::Imgix;
+/// use image_rs::common::{ImageLoader, LoaderProps};
+///
+/// let loader = Imgix::new("https://example.imgix.net");
+/// let props = LoaderProps { src: "/hero.jpg", width: 640, ..Default::default() };
+/// assert_eq!(
+///     loader.url(props),
+///     "https://example.imgix.net/hero.jpg?auto=format&fit=clip&w=640"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Imgix {
+    domain: String,
+}
+
+impl Imgix {
+    /// Creates a loader for the given imgix source domain, e.g. `https://example.imgix.net`.
+    pub fn new(domain: impl Into<String>) -> Self {
+        Imgix {
+            domain: domain.into(),
+        }
+    }
+}
+
+/// Maps `object-fit` onto imgix's `fit` parameter.
+fn fit(object_fit: ObjectFit) -> &'static str {
+    match object_fit {
+        ObjectFit::Fill => "scale",
+        ObjectFit::Contain => "clip",
+        ObjectFit::Cover => "crop",
+        ObjectFit::ScaleDown | ObjectFit::None => "max",
+    }
+}
+
+/// Maps `object-position` onto imgix's `crop` parameter, `None` meaning centered.
+fn crop(position: Position) -> Option<&'static str> {
+    match position {
+        Position::Center => None,
+        Position::Top => Some("top"),
+        Position::Bottom => Some("bottom"),
+        Position::Left => Some("left"),
+        Position::Right => Some("right"),
+        Position::TopLeft => Some("top,left"),
+        Position::TopRight => Some("top,right"),
+        Position::BottomLeft => Some("bottom,left"),
+        Position::BottomRight => Some("bottom,right"),
+    }
+}
+
+impl ImageLoader for Imgix {
+    fn url(&self, props: LoaderProps<'_>) -> String {
+        let path = if is_absolute(props.src) {
+            encode_uri_component(props.src)
+        } else {
+            normalize_src(props.src).to_string()
+        };
+
+        let height = props.crop_height();
+        let mut params = vec!["auto=format".to_string()];
+        match (props.object_fit, height) {
+            // Without a height, the aspect ratio is kept and there is nothing to crop.
+            (ObjectFit::Cover | ObjectFit::Fill, None) => {}
+            (ObjectFit::Cover, Some(_)) => {
+                params.push(format!("fit={}", fit(props.object_fit)));
+                params.extend(crop(props.object_position).map(|crop| format!("crop={crop}")));
+            }
+            (object_fit, _) => params.push(format!("fit={}", fit(object_fit))),
+        }
+        params.push(format!("w={}", props.width));
+        params.extend(height.map(|height| format!("h={height}")));
+        if let Some(quality) = props.quality {
+            params.push(format!("q={quality}"));
+        }
+
+        format!("{}/{}?{}", trim_base(&self.domain), path, params.join("&"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_fit_and_position_to_fit_and_crop() {
+        let loader = Imgix::new("https://example.imgix.net/");
+        let props = LoaderProps {
+            src: "/photos/hero.jpg",
+            width: 828,
+            height: Some(552),
+            quality: Some(60),
+            object_fit: ObjectFit::Cover,
+            object_position: Position::BottomLeft,
+        };
+        assert_eq!(
+            loader.url(props),
+            "https://example.imgix.net/photos/hero.jpg?auto=format&fit=crop&crop=bottom,left&w=828&h=552&q=60"
+        );
+
+        let fill = LoaderProps {
+            object_fit: ObjectFit::Fill,
+            ..props
+        };
+        assert_eq!(
+            loader.url(fill),
+            "https://example.imgix.net/photos/hero.jpg?auto=format&fit=scale&w=828&h=552&q=60"
+        );
+
+        let unknown = LoaderProps {
+            height: None,
+            ..props
+        };
+        assert_eq!(
+            loader.url(unknown),
+            "https://example.imgix.net/photos/hero.jpg?auto=format&w=828&q=60"
+        );
+    }
+
+    #[test]
+    fn encodes_absolute_urls_into_the_path() {
+        let loader = Imgix::new("https://proxy.imgix.net");
+        let props = LoaderProps {
+            src: "https://example.com/hero.jpg",
+            width: 640,
+            object_fit: ObjectFit::ScaleDown,
+            ..Default::default()
+        };
+        assert_eq!(
+            loader.url(props),
+            "https://proxy.imgix.net/https%3A%2F%2Fexample.com%2Fhero.jpg?auto=format&fit=max&w=640"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use super::{encode_uri_component, is_absolute, normalize_src, trim_base};
+use crate::common::{ImageLoader, LoaderProps, ObjectFit, Position};
+
+/// Loader for [imgproxy](https://docs.imgproxy.net/usage/processing).
+///
+/// URLs are generated unsigned (`/insecure/`), so the imgproxy instance must run without
+/// `IMGPROXY_KEY`/`IMGPROXY_SALT`. Relative sources are resolved by imgproxy against its
+/// `IMGPROXY_BASE_URL`.
+///
+/// # Example
+/// ```rust
+/// use image_rs::common::loaders::Imgproxy;
+/// use image_rs::common::{ImageLoader, LoaderProps};
+///
+/// let loader = Imgproxy::new("https://imgproxy.example.com");
+/// let props = LoaderProps { src: "/hero.jpg", width: 640, ..Default::default() };
+/// assert_eq!(
+///     loader.url(props),
+///     "https://imgproxy.example.com/insecure/rs:fit:640:0/plain/hero.jpg"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Imgproxy {
+    base_url: String,
+}
+
+impl Imgproxy {
+    /// Creates a loader for the imgproxy instance at `base_url`.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Imgproxy {
+            base_url: base_url.into(),
+        }
+    }
+}
+
+/// Maps `object-fit` onto imgproxy's resizing type.
+fn resizing_type(object_fit: ObjectFit) -> &'static str {
+    match object_fit {
+        ObjectFit::Fill => "force",
+        ObjectFit::Cover => "fill",
+        ObjectFit::Contain | ObjectFit::ScaleDown | ObjectFit::None => "fit",
+    }
+}
+
+/// Maps `object-position` onto imgproxy's gravity.
+fn gravity(position: Position) -> &'static str {
+    match position {
+        Position::Center => "ce",
+        Position::Top => "no",
+        Position::Bottom => "so",
+        Position::Left => "we",
+        Position::Right => "ea",
+        Position::TopLeft => "nowe",
+        Position::TopRight => "noea",
+        Position::BottomLeft => "sowe",
+        Position::BottomRight => "soea",
+    }
+}
+
+impl ImageLoader for Imgproxy {
+    fn url(&self, props: LoaderProps<'_>) -> String {
+        let height = props.crop_height();
+        let mut options = vec![format!(
+            "rs:{}:{}:{}",
+            resizing_type(props.object_fit),
+            props.width,
+            height.unwrap_or_default()
+        )];
+        if props.object_fit == ObjectFit::Cover
+            && props.object_position != Position::Center
+            && height.is_some()
+        {
+            options.push(format!("g:{}", gravity(props.object_position)));
+        }
+        if let Some(quality) = props.quality {
+            options.push(format!("q:{quality}"));
+        }
+
+        let src = if is_absolute(props.src) {
+            encode_uri_component(props.src)
+        } else {
+            normalize_src(props.src).to_string()
+        };
+
+        format!(
+            "{}/insecure/{}/plain/{}",
+            trim_base(&self.base_url),
+            options.join("/"),
+            src
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_fit_and_position_to_resizing_type_and_gravity() {
+        let loader = Imgproxy::new("https://imgproxy.example.com/");
+        let props = LoaderProps {
+            src: "https://example.com/hero.jpg",
+            width: 1200,
+            height: Some(630),
+            quality: Some(75),
+            object_fit: ObjectFit::Cover,
+            object_position: Position::Top,
+        };
+        assert_eq!(
+            loader.url(props),
+            "https://imgproxy.example.com/insecure/rs:fill:1200:630/g:no/q:75/plain/https%3A%2F%2Fexample.com%2Fhero.jpg"
+        );
+    }
+
+    #[test]
+    fn stretches_for_fill() {
+        let loader = Imgproxy::new("https://imgproxy.example.com");
+        let props = LoaderProps {
+            src: "hero.jpg",
+            width: 320,
+            height: Some(240),
+            object_fit: ObjectFit::Fill,
+            ..Default::default()
+        };
+        assert_eq!(
+            loader.url(props),
+            "https://imgproxy.example.com/insecure/rs:force:320:240/plain/hero.jpg"
+        );
+    }
+
+    #[test]
+    fn skips_gravity_without_height() {
+        let loader = Imgproxy::new("https://imgproxy.example.com");
+        let props = LoaderProps {
+            src: "hero.jpg",
+            width: 1200,
+            object_fit: ObjectFit::Cover,
+            object_position: Position::Top,
+            ..Default::default()
+        };
+        assert_eq!(
+            loader.url(props),
+            "https://imgproxy.example.com/insecure/rs:fill:1200:0/plain/hero.jpg"
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use super::{encode_uri_component, is_absolute, normalize_src, trim_base};
+use crate::common::{ImageLoader, LoaderProps, ObjectFit, Position};
+
+/// Loader for [Thumbor](https://thumbor.readthedocs.io/en/latest/usage.html).
+///
+/// URLs are generated unsigned (`/unsafe/`), so the Thumbor server must have
+/// `ALLOW_UNSAFE_URL` enabled.
+///
+/// # Example
+/// ```rust
+/// use image_rs::common::loaders::Thumbor;
+/// use image_rs::common::{ImageLoader, LoaderProps};
+///
+/// let loader = Thumbor::new("https://thumbor.example.com");
+/// let props = LoaderProps { src: "/hero.jpg", width: 640, ..Default::default() };
+/// assert_eq!(
+///     loader.url(props),
+///     "https://thumbor.example.com/unsafe/fit-in/640x0/hero.jpg"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Thumbor {
+    base_url: String,
+}
+
+impl Thumbor {
+    /// Creates a loader for the Thumbor server at `base_url`.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Thumbor {
+            base_url: base_url.into(),
+        }
+    }
+}
+
+/// Maps `object-position` onto Thumbor's horizontal and vertical alignment.
+///
+/// Thumbor centers crops by default, so centered axes are omitted.
+fn alignment(position: Position) -> (Option<&'static str>, Option<&'static str>) {
+    match position {
+        Position::Center => (None, None),
+        Position::Top => (None, Some("top")),
+        Position::Bottom => (None, Some("bottom")),
+        Position::Left => (Some("left"), None),
+        Position::Right => (Some("right"), None),
+        Position::TopLeft => (Some("left"), Some("top")),
+        Position::TopRight => (Some("right"), Some("top")),
+        Position::BottomLeft => (Some("left"), Some("bottom")),
+        Position::BottomRight => (Some("right"), Some("bottom")),
+    }
+}
+
+impl ImageLoader for Thumbor {
+    fn url(&self, props: LoaderProps<'_>) -> String {
+        let mut segments = Vec::new();
+        match (props.object_fit, props.crop_height()) {
+            (ObjectFit::Contain | ObjectFit::ScaleDown | ObjectFit::None, _) => {
+                segments.push("fit-in".to_string());
+                segments.push(format!("{}x0", props.width));
+            }
+            (ObjectFit::Fill, Some(height)) => {
+                segments.push("stretch".to_string());
+                segments.push(format!("{}x{}", props.width, height));
+            }
+            (ObjectFit::Cover, Some(height)) => {
+                segments.push(format!("{}x{}", props.width, height));
+                let (halign, valign) = alignment(props.object_position);
+                segments.extend(halign.map(str::to_string));
+                segments.extend(valign.map(str::to_string));
+            }
+            // Without a height, the aspect ratio is kept and there is nothing to crop.
+            (ObjectFit::Fill | ObjectFit::Cover, None) => {
+                segments.push(format!("{}x0", props.width));
+            }
+        }
+        if let Some(quality) = props.quality {
+            segments.push(format!("filters:quality({quality})"));
+        }
+
+        let src = if is_absolute(props.src) {
+            encode_uri_component(props.src)
+        } else {
+            normalize_src(props.src).to_string()
+        };
+
+        format!(
+            "{}/unsafe/{}/{}",
+            trim_base(&self.base_url),
+            segments.join("/"),
+            src
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_fit_and_position_to_alignment() {
+        let loader = Thumbor::new("https://thumbor.example.com/");
+        let props = LoaderProps {
+            src: "https://example.com/hero.jpg",
+            width: 750,
+            height: Some(500),
+            quality: Some(85),
+            object_fit: ObjectFit::Cover,
+            object_position: Position::BottomRight,
+        };
+        assert_eq!(
+            loader.url(props),
+            "https://thumbor.example.com/unsafe/750x500/right/bottom/filters:quality(85)/https%3A%2F%2Fexample.com%2Fhero.jpg"
+        );
+
+        let fill = LoaderProps {
+            object_fit: ObjectFit::Fill,
+            ..props
+        };
+        assert_eq!(
+            loader.url(fill),
+            "https://thumbor.example.com/unsafe/stretch/750x500/filters:quality(85)/https%3A%2F%2Fexample.com%2Fhero.jpg"
+        );
+    }
+
+    #[test]
+    fn skips_alignment_without_height() {
+        let loader = Thumbor::new("https://thumbor.example.com");
+        let props = LoaderProps {
+            src: "/hero.jpg",
+            width: 750,
+            object_fit: ObjectFit::Cover,
+            object_position: Position::BottomRight,
+            ..Default::default()
+        };
+        assert_eq!(
+            loader.url(props),
+            "https://thumbor.example.com/unsafe/750x0/hero.jpg"
+        );
+    }
+}
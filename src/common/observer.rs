@@ -0,0 +1,200 @@
+use super::lazy::LazyConfig;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use web_sys::js_sys;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::wasm_bindgen::prelude::*;
+use web_sys::{Element, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+type Handler = Box<dyn FnOnce()>;
+
+thread_local! {
+    /// The observers in use, one per set of options.
+    static POOLS: RefCell<Vec<Rc<Pool>>> = const { RefCell::new(Vec::new()) };
+    static NEXT_ID: Cell<u32> = const { Cell::new(0) };
+}
+
+/// An `IntersectionObserver` shared by every element observed with the same options.
+struct Pool {
+    root: Option<Element>,
+    root_margin: String,
+    thresholds: Vec<f64>,
+    observer: IntersectionObserver,
+    targets: Rc<Targets>,
+    _callback: Closure<dyn FnMut(js_sys::Array, IntersectionObserver)>,
+}
+
+/// The elements observed by a [`Pool`] and the handlers to run when they become visible.
+struct Targets {
+    /// Share of an element that must intersect for it to be visible.
+    threshold: f64,
+    /// Maps every observed element to the id of its handler.
+    ids: js_sys::Map,
+    handlers: RefCell<HashMap<u32, Handler>>,
+}
+
+impl Targets {
+    /// Stops observing `element` for the handler `id`, returning the handler if it did not run.
+    fn release(
+        &self,
+        observer: &IntersectionObserver,
+        element: &Element,
+        id: u32,
+    ) -> Option<Handler> {
+        if self.ids.get(element).as_f64() == Some(f64::from(id)) {
+            self.ids.delete(element);
+            observer.unobserve(element);
+        }
+        self.handlers.borrow_mut().remove(&id)
+    }
+
+    /// Runs the handlers of the elements that became visible, each at most once.
+    fn dispatch(&self, entries: js_sys::Array, observer: &IntersectionObserver) {
+        let visible: Vec<Handler> = entries
+            .iter()
+            .filter_map(|entry| entry.dyn_into::<IntersectionObserverEntry>().ok())
+            .filter(|entry| entry.is_intersecting() && entry.intersection_ratio() >= self.threshold)
+            .filter_map(|entry| {
+                let target = entry.target();
+                let id = self.ids.get(&target).as_f64()? as u32;
+                self.release(observer, &target, id)
+            })
+            .collect();
+        // Handlers run after the borrow is released, as they may observe other elements.
+        for handler in visible {
+            handler();
+        }
+    }
+}
+
+impl Pool {
+    fn new(root: Option<Element>, config: &LazyConfig) -> Option<Rc<Self>> {
+        let targets = Rc::new(Targets {
+            threshold: config.min_threshold(),
+            ids: js_sys::Map::new(),
+            handlers: RefCell::new(HashMap::new()),
+        });
+        let callback = {
+            let targets = targets.clone();
+            Closure::wrap(Box::new(
+                move |entries: js_sys::Array, observer: IntersectionObserver| {
+                    targets.dispatch(entries, &observer);
+                },
+            )
+                as Box<dyn FnMut(js_sys::Array, IntersectionObserver)>)
+        };
+
+        let options = IntersectionObserverInit::new();
+        let thresholds: js_sys::Array = config
+            .thresholds
+            .iter()
+            .map(|&t| JsValue::from(t))
+            .collect();
+        options.set_threshold(&thresholds);
+        if !config.root_margin.is_empty() {
+            options.set_root_margin(&config.root_margin);
+        }
+        if let Some(root) = &root {
+            options.set_root(Some(root));
+        }
+        let observer =
+            IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &options)
+                .ok()?;
+
+        Some(Rc::new(Pool {
+            root,
+            root_margin: config.root_margin.clone(),
+            thresholds: config.thresholds.clone(),
+            observer,
+            targets,
+            _callback: callback,
+        }))
+    }
+
+    fn matches(&self, root: Option<&Element>, config: &LazyConfig) -> bool {
+        self.root.as_ref() == root
+            && self.root_margin == config.root_margin
+            && self.thresholds == config.thresholds
+    }
+}
+
+impl Drop for Pool {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// An element being watched by [`observe_visibility`].
+///
+/// Dropping it stops observing the element. The shared observer is disconnected and its
+/// closure freed once no element uses it anymore.
+#[must_use = "the element stops being observed when the observation is dropped"]
+pub struct Observation {
+    pool: Rc<Pool>,
+    element: Element,
+    id: u32,
+}
+
+impl Drop for Observation {
+    fn drop(&mut self) {
+        let pool = &self.pool;
+        drop(pool.targets.release(&pool.observer, &self.element, self.id));
+        if pool.targets.handlers.borrow().is_empty() {
+            let _ = POOLS.try_with(|pools| {
+                pools
+                    .borrow_mut()
+                    .retain(|pool| !Rc::ptr_eq(pool, &self.pool));
+            });
+        }
+    }
+}
+
+/// Calls `on_visible` once `element` intersects the root of `config`, extended by its
+/// `root_margin`, for at least the smallest of its `thresholds`.
+///
+/// Elements observed against the same root, with the same `root_margin` and `thresholds`, share
+/// a single `IntersectionObserver`. Each element is unobserved as soon as it becomes visible, so
+/// `on_visible` runs at most once. Returns `None` if the observer could not be created.
+pub fn observe_visibility(
+    element: &Element,
+    config: &LazyConfig,
+    on_visible: impl FnOnce() + 'static,
+) -> Option<Observation> {
+    let root = config.root.resolve(element);
+    let pool = POOLS.with(|pools| {
+        let existing = pools
+            .borrow()
+            .iter()
+            .find(|pool| pool.matches(root.as_ref(), config))
+            .cloned();
+        existing.or_else(|| {
+            let pool = Pool::new(root, config)?;
+            pools.borrow_mut().push(pool.clone());
+            Some(pool)
+        })
+    })?;
+
+    let id = NEXT_ID.with(|next| {
+        let id = next.get();
+        next.set(id.wrapping_add(1));
+        id
+    });
+    // An element observed again replaces its previous handler.
+    let targets = &pool.targets;
+    if let Some(previous) = targets.ids.get(element).as_f64() {
+        targets.handlers.borrow_mut().remove(&(previous as u32));
+    }
+    targets
+        .handlers
+        .borrow_mut()
+        .insert(id, Box::new(on_visible));
+    targets.ids.set(element, &JsValue::from(id));
+    pool.observer.observe(element);
+
+    Some(Observation {
+        pool,
+        element: element.clone(),
+        id,
+    })
+}
@@ -0,0 +1,80 @@
+/// A `<source>` of a `<picture>`, offering another format or crop of the image.
+///
+/// The browser renders the first source whose `media` matches and whose `type` it supports,
+/// and the `<img>` otherwise. Sources follow the image: they are only rendered once a lazy
+/// image is in view, and dropped once a fallback is displayed.
+///
+/// # Example
+/// ```rust
+/// use image_rs::Source;
+///
+/// // AVIF, then WebP, then the JPEG `src` of the image.
+/// let sources = vec![
+///     Source::avif("/hero.avif"),
+///     Source::webp("/hero.webp"),
+/// ];
+/// // A square crop on narrow screens.
+/// let mobile = Source::new("/hero-square.jpg").with_media("(max-width: 640px)");
+/// assert_eq!(sources[0].mime_type, "image/avif");
+/// assert_eq!(mobile.media, "(max-width: 640px)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Source {
+    /// The `media` query selecting the source, empty to match any viewport.
+    pub media: String,
+
+    /// The `type` of the source, e.g. `image/avif`, empty to let the browser sniff it.
+    pub mime_type: String,
+
+    /// The `srcset` of the source.
+    pub srcset: String,
+
+    /// The `sizes` of the source, defaulting to the `sizes` of the image.
+    pub sizes: String,
+}
+
+impl Source {
+    /// Creates a source matching any viewport and format.
+    pub fn new(srcset: impl Into<String>) -> Self {
+        Source {
+            srcset: srcset.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates an AVIF source.
+    pub fn avif(srcset: impl Into<String>) -> Self {
+        Source::new(srcset).with_mime_type("image/avif")
+    }
+
+    /// Creates a WebP source.
+    pub fn webp(srcset: impl Into<String>) -> Self {
+        Source::new(srcset).with_mime_type("image/webp")
+    }
+
+    /// Sets the `media` query selecting the source.
+    pub fn with_media(mut self, media: impl Into<String>) -> Self {
+        self.media = media.into();
+        self
+    }
+
+    /// Sets the `type` of the source.
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = mime_type.into();
+        self
+    }
+
+    /// Sets the `sizes` of the source.
+    pub fn with_sizes(mut self, sizes: impl Into<String>) -> Self {
+        self.sizes = sizes.into();
+        self
+    }
+}
+
+/// Returns the value of an optional attribute, `None` to leave it out when empty.
+///
+/// An empty `type` would make the browser skip the source.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn non_empty(value: &str) -> Option<String> {
+    (!value.is_empty()).then(|| value.to_string())
+}
@@ -0,0 +1,266 @@
+use super::Position;
+use super::blurhash;
+use super::canvas::rgba_to_data_url;
+use super::thumbhash;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+thread_local! {
+    /// Whether the keyframes of the skeleton placeholder were injected into the document head.
+    static SKELETON_KEYFRAMES: Cell<bool> = const { Cell::new(false) };
+    /// Data URLs of the hashes already decoded, so each hash is painted once per page.
+    static DATA_URLS: RefCell<HashMap<String, Option<String>>> = RefCell::new(HashMap::new());
+}
+
+/// Width and height of the image a hash is decoded to.
+const HASH_SIZE: u32 = 32;
+
+/// Name of the animation of the skeleton placeholder.
+const SKELETON_ANIMATION: &str = "image-rs-skeleton";
+
+/// What is rendered behind the image until it is decoded.
+///
+/// `V` is the view type of the framework, rendered by [`Placeholder::Custom`]: `Html` for Yew,
+/// `Element` for Dioxus and `ViewFn` for Leptos.
+///
+/// # Example
+/// ```rust
+/// use image_rs::Placeholder;
+///
+/// let placeholder: Placeholder = Placeholder::Color("#d4d4d8".into());
+/// assert!(placeholder.underlay_style(Default::default(), false).is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Placeholder<V = ()> {
+    /// Nothing is rendered (default).
+    #[default]
+    Empty,
+
+    /// A low-quality version of the image, usually a small data URL, blurred behind the image.
+    Blur(String),
+
+    /// A solid CSS color, e.g. the dominant color of the image.
+    Color(String),
+
+    /// Another image, e.g. a thumbnail, covering the area of the image.
+    Image(String),
+
+    /// A BlurHash string of the image.
+    BlurHash(String),
+
+    /// The bytes of a ThumbHash of the image, see [`Placeholder::thumbhash_base64`].
+    ThumbHash(Vec<u8>),
+
+    /// A pulsing gray block.
+    Skeleton,
+
+    /// A view of the framework, e.g. a spinner, rendered in a layer behind the image.
+    Custom(V),
+}
+
+impl<V> Placeholder<V> {
+    /// Creates a [`Placeholder::ThumbHash`] from its base64 encoding, as produced by most
+    /// ThumbHash encoders. An invalid string renders no placeholder.
+    pub fn thumbhash_base64(hash: &str) -> Self {
+        thumbhash::from_base64(hash).map_or(Placeholder::Empty, Placeholder::ThumbHash)
+    }
+
+    /// Returns `true` for [`Placeholder::Custom`], which is always rendered in its own layer.
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Placeholder::Custom(_))
+    }
+
+    /// Returns `true` for [`Placeholder::Skeleton`], whose animation is injected into the
+    /// document head by [`inject_skeleton_keyframes`].
+    pub fn is_skeleton(&self) -> bool {
+        matches!(self, Placeholder::Skeleton)
+    }
+
+    /// Returns the approximate aspect ratio, width over height, of the image of a
+    /// [`Placeholder::ThumbHash`], used to reserve its space when its size is unknown.
+    pub fn aspect_ratio(&self) -> Option<f64> {
+        match self {
+            Placeholder::ThumbHash(hash) => thumbhash::aspect_ratio(hash).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the inline style painting the placeholder as a background, `None` for
+    /// [`Placeholder::Empty`], [`Placeholder::Custom`] and invalid hashes.
+    ///
+    /// A BlurHash or ThumbHash is decoded and painted as a data URL in the browser, and
+    /// rendered as the average color of the image elsewhere. ThumbHashes keep their alpha
+    /// channel, so transparent logos are not painted over a box.
+    pub fn underlay_style(
+        &self,
+        object_position: Position,
+        reduced_motion: bool,
+    ) -> Option<String> {
+        let cover = |url: &str| {
+            format!(
+                "background-size: cover; background-position: {}; background-repeat: no-repeat; background-image: url(\"{url}\");",
+                object_position.as_str()
+            )
+        };
+        match self {
+            Placeholder::Empty | Placeholder::Custom(_) => None,
+            Placeholder::Blur(url) if url.is_empty() => None,
+            Placeholder::Blur(url) => Some(format!("{} filter: blur(20px);", cover(url))),
+            Placeholder::Color(color) if color.is_empty() => None,
+            Placeholder::Color(color) => Some(format!("background-color: {color};")),
+            Placeholder::Image(url) if url.is_empty() => None,
+            Placeholder::Image(url) => Some(cover(url)),
+            Placeholder::BlurHash(hash) => blurhash_data_url(hash)
+                .map(|url| cover(&url))
+                .or_else(|| blurhash::average_color(hash).ok().map(background_color)),
+            Placeholder::ThumbHash(hash) => thumbhash_data_url(hash)
+                .map(|url| cover(&url))
+                .or_else(|| thumbhash::average_color(hash).ok().map(background_color)),
+            Placeholder::Skeleton if reduced_motion => Some("background-color: #e5e7eb;".into()),
+            Placeholder::Skeleton => Some(format!(
+                "background-color: #e5e7eb; animation: {SKELETON_ANIMATION} 1.5s ease-in-out infinite;"
+            )),
+        }
+    }
+}
+
+/// Returns the `background-color` of an RGBA color.
+fn background_color([r, g, b, a]: [u8; 4]) -> String {
+    if a == u8::MAX {
+        format!("background-color: rgb({r}, {g}, {b});")
+    } else {
+        format!(
+            "background-color: rgba({r}, {g}, {b}, {:.3});",
+            f64::from(a) / 255.0
+        )
+    }
+}
+
+/// Returns the data URL of `hash` decoded, `None` if it is invalid or outside of a browser.
+fn blurhash_data_url(hash: &str) -> Option<String> {
+    cached_data_url(hash, || {
+        let pixels = blurhash::decode(hash, HASH_SIZE, HASH_SIZE, 1.0).ok()?;
+        rgba_to_data_url(&pixels, HASH_SIZE, HASH_SIZE)
+    })
+}
+
+/// Returns the PNG data URL of `hash` decoded with its alpha channel, `None` if it is invalid
+/// or outside of a browser.
+fn thumbhash_data_url(hash: &[u8]) -> Option<String> {
+    // Hex keeps the keys of both hash kinds apart, as it is not valid base 83.
+    let key: String = hash.iter().map(|byte| format!("{byte:02x}")).collect();
+    cached_data_url(&format!("thumbhash:{key}"), || {
+        let (pixels, width, height) = thumbhash::decode(hash).ok()?;
+        rgba_to_data_url(&pixels, width, height)
+    })
+}
+
+/// Returns the data URL painted for `key`, painting it with `paint` the first time.
+fn cached_data_url(key: &str, paint: impl FnOnce() -> Option<String>) -> Option<String> {
+    if let Some(url) = DATA_URLS.with(|urls| urls.borrow().get(key).cloned()) {
+        return url;
+    }
+    let url = paint();
+    DATA_URLS.with(|urls| urls.borrow_mut().insert(key.to_string(), url.clone()));
+    url
+}
+
+/// Injects the keyframes of [`Placeholder::Skeleton`] into the document head, once.
+pub fn inject_skeleton_keyframes() {
+    if SKELETON_KEYFRAMES.with(|injected| injected.replace(true)) {
+        return;
+    }
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let (Some(head), Ok(style)) = (document.head(), document.create_element("style")) else {
+        return;
+    };
+    style.set_text_content(Some(&format!(
+        "@keyframes {SKELETON_ANIMATION} {{ 0%, 100% {{ opacity: 1; }} 50% {{ opacity: 0.5; }} }}"
+    )));
+    let _ = head.append_child(&style);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashes_render_their_average_color() {
+        // Outside of a browser, a BlurHash is not painted.
+        let position = Position::default();
+        assert_eq!(
+            Placeholder::<()>::BlurHash("LEHV6nWB2yk8pyo0adR*.7kCMdnj".into())
+                .underlay_style(position, false)
+                .as_deref(),
+            Some("background-color: rgb(151, 150, 149);")
+        );
+        assert_eq!(
+            Placeholder::<()>::BlurHash("LE".into()).underlay_style(position, false),
+            None
+        );
+
+        // A light, slightly warm and opaque image.
+        assert_eq!(
+            Placeholder::<()>::ThumbHash(vec![0x3f, 0x08, 0x02, 0, 0])
+                .underlay_style(position, false)
+                .as_deref(),
+            Some("background-color: rgb(255, 254, 252);")
+        );
+        assert_eq!(
+            Placeholder::<()>::ThumbHash(vec![1, 2]).underlay_style(position, false),
+            None
+        );
+    }
+
+    #[test]
+    fn thumbhashes_keep_their_aspect_ratio_and_alpha() {
+        let portrait: Placeholder = Placeholder::thumbhash_base64("1QcSHQRnh493V4dIh4eXh1h4kJUI");
+        assert_eq!(portrait.aspect_ratio(), Some(5.0 / 7.0));
+
+        let logo: Placeholder = Placeholder::thumbhash_base64("YJqGPQw7sFlslqhFafSE+Q6oJ1h2iHB2Rw");
+        assert_eq!(logo.aspect_ratio(), Some(1.0));
+        assert!(
+            logo.underlay_style(Position::default(), false)
+                .unwrap()
+                .starts_with("background-color: rgba(")
+        );
+
+        assert_eq!(
+            Placeholder::<()>::thumbhash_base64("not base64!"),
+            Placeholder::Empty
+        );
+        assert_eq!(Placeholder::<()>::Color("red".into()).aspect_ratio(), None);
+    }
+
+    #[test]
+    fn empty_and_custom_have_no_underlay() {
+        let position = Position::default();
+        assert_eq!(
+            Placeholder::<()>::Empty.underlay_style(position, false),
+            None
+        );
+        assert_eq!(
+            Placeholder::Custom(()).underlay_style(position, false),
+            None
+        );
+        assert_eq!(
+            Placeholder::<()>::Blur(String::new()).underlay_style(position, false),
+            None
+        );
+        let skeleton = Placeholder::<()>::Skeleton;
+        assert!(
+            skeleton
+                .underlay_style(position, false)
+                .unwrap()
+                .contains("animation")
+        );
+        assert!(
+            !skeleton
+                .underlay_style(position, true)
+                .unwrap()
+                .contains("animation")
+        );
+    }
+}
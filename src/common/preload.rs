@@ -0,0 +1,58 @@
+use super::{CrossOrigin, ReferrerPolicy};
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+thread_local! {
+    /// Keys of the images already preloaded by any `Image` instance.
+    static PRELOADED: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+}
+
+/// Injects a `<link rel="preload" as="image">` for a priority image into the document head.
+///
+/// The link is only injected once per `src`, `srcset` and `sizes` combination, no matter how
+/// many `Image` instances request it. As in Next.js, `href` is omitted when a `srcset` is
+/// provided so the browser picks the same candidate as the `<img>`.
+pub fn preload_image(
+    src: &str,
+    srcset: &str,
+    sizes: &str,
+    crossorigin: &CrossOrigin,
+    referrerpolicy: ReferrerPolicy,
+) {
+    if src.is_empty() && srcset.is_empty() {
+        return;
+    }
+
+    let key = format!("{src}|{srcset}|{sizes}");
+    if !PRELOADED.with(|preloaded| preloaded.borrow_mut().insert(key)) {
+        return;
+    }
+
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let Some(head) = document.head() else {
+        return;
+    };
+    let Ok(link) = document.create_element("link") else {
+        return;
+    };
+
+    let _ = link.set_attribute("rel", "preload");
+    let _ = link.set_attribute("as", "image");
+    let _ = link.set_attribute("fetchpriority", "high");
+    if srcset.is_empty() {
+        let _ = link.set_attribute("href", src);
+    } else {
+        let _ = link.set_attribute("imagesrcset", srcset);
+        if !sizes.is_empty() {
+            let _ = link.set_attribute("imagesizes", sizes);
+        }
+    }
+    if let Some(crossorigin) = crossorigin.as_str() {
+        let _ = link.set_attribute("crossorigin", crossorigin);
+    }
+    let _ = link.set_attribute("referrerpolicy", referrerpolicy.as_str());
+
+    let _ = head.append_child(&link);
+}
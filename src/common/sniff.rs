@@ -0,0 +1,442 @@
+//! Image format detection from the `Content-Type` header and the magic bytes of a response.
+//!
+//! Used to make sure a fallback is an actual image before it replaces `src`, rather than an
+//! HTML error page served with a `200` status.
+
+/// Image formats recognized by [`sniff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// PNG, starting with `\x89PNG\r\n\x1a\n`.
+    Png,
+
+    /// JPEG, starting with the `FF D8 FF` start of image marker.
+    Jpeg,
+
+    /// GIF, starting with `GIF87a` or `GIF89a`.
+    Gif,
+
+    /// WebP, a `RIFF` container whose form type is `WEBP`.
+    WebP,
+
+    /// AVIF, an `ftyp` box listing the `avif` or `avis` brand.
+    Avif,
+
+    /// SVG, a text document whose root element is `<svg>`.
+    Svg,
+}
+
+impl ImageFormat {
+    /// Returns the MIME type of the format.
+    pub fn mime(&self) -> &'static str {
+        match self {
+            ImageFormat::Png => "image/png",
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Gif => "image/gif",
+            ImageFormat::WebP => "image/webp",
+            ImageFormat::Avif => "image/avif",
+            ImageFormat::Svg => "image/svg+xml",
+        }
+    }
+}
+
+/// Format and intrinsic size of an image, read from its header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageInfo {
+    /// The detected format.
+    pub format: ImageFormat,
+
+    /// The `(width, height)` in pixels, `None` if the header does not declare it.
+    pub dimensions: Option<(u32, u32)>,
+}
+
+/// Detects an image from its `Content-Type` and first bytes.
+///
+/// The magic bytes are authoritative: a mislabeled PNG served as `application/octet-stream`
+/// is accepted. A `Content-Type` that is neither `image/*` nor `application/octet-stream`
+/// (e.g. `text/html`) is rejected regardless of the body.
+///
+/// # Example
+/// ```rust
+/// use image_rs::common::sniff::{ImageFormat, sniff};
+///
+/// let gif = b"GIF89a\x20\x00\x10\x00";
+/// let info = sniff(Some("image/gif"), gif).unwrap();
+/// assert_eq!(info.format, ImageFormat::Gif);
+/// assert_eq!(info.dimensions, Some((32, 16)));
+///
+/// assert_eq!(sniff(Some("text/html"), gif), None);
+/// ```
+pub fn sniff(content_type: Option<&str>, bytes: &[u8]) -> Option<ImageInfo> {
+    if let Some(content_type) = content_type {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        if !mime.is_empty() && !mime.starts_with("image/") && mime != "application/octet-stream" {
+            return None;
+        }
+    }
+
+    let format = detect_format(bytes)?;
+    Some(ImageInfo {
+        format,
+        dimensions: dimensions(format, bytes),
+    })
+}
+
+/// Detects the format of an image from its magic bytes.
+pub fn detect_format(bytes: &[u8]) -> Option<ImageFormat> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some(ImageFormat::Png)
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some(ImageFormat::Jpeg)
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some(ImageFormat::Gif)
+    } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        Some(ImageFormat::WebP)
+    } else if is_avif(bytes) {
+        Some(ImageFormat::Avif)
+    } else if is_svg(bytes) {
+        Some(ImageFormat::Svg)
+    } else {
+        None
+    }
+}
+
+/// Reads the `(width, height)` declared in the header of an image.
+pub fn dimensions(format: ImageFormat, bytes: &[u8]) -> Option<(u32, u32)> {
+    match format {
+        ImageFormat::Png => {
+            if bytes.get(12..16)? != b"IHDR" {
+                return None;
+            }
+            Some((be_u32(bytes, 16)?, be_u32(bytes, 20)?))
+        }
+        ImageFormat::Gif => Some((u32::from(le_u16(bytes, 6)?), u32::from(le_u16(bytes, 8)?))),
+        ImageFormat::Jpeg => jpeg_dimensions(bytes),
+        ImageFormat::WebP => webp_dimensions(bytes),
+        ImageFormat::Avif => avif_dimensions(bytes),
+        ImageFormat::Svg => svg_dimensions(bytes),
+    }
+}
+
+fn be_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn le_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn be_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        bytes.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn le_u24(bytes: &[u8], offset: usize) -> Option<u32> {
+    let b = bytes.get(offset..offset + 3)?;
+    Some(u32::from(b[0]) | u32::from(b[1]) << 8 | u32::from(b[2]) << 16)
+}
+
+/// Checks the `ftyp` box for an AVIF major or compatible brand.
+fn is_avif(bytes: &[u8]) -> bool {
+    if bytes.get(4..8) != Some(b"ftyp") {
+        return false;
+    }
+    let size = be_u32(bytes, 0).unwrap_or_default() as usize;
+    let end = size.clamp(8, bytes.len());
+    bytes[8.min(end)..end]
+        .chunks_exact(4)
+        .enumerate()
+        // Skip the minor version that follows the major brand.
+        .filter(|(index, _)| *index != 1)
+        .any(|(_, brand)| brand == b"avif" || brand == b"avis")
+}
+
+/// Requires the root element to be `<svg>`, after a BOM, an XML declaration, comments and a
+/// doctype, so an HTML page embedding an inline `<svg>` is rejected.
+fn is_svg(bytes: &[u8]) -> bool {
+    let head = &bytes[..bytes.len().min(1024)];
+    let Ok(text) = std::str::from_utf8(head).or_else(|err| {
+        // The 1024 bytes may cut a multi-byte character in half.
+        std::str::from_utf8(&head[..err.valid_up_to()])
+    }) else {
+        return false;
+    };
+    let Some(root) = root_element(text.trim_start_matches('\u{feff}')) else {
+        return false;
+    };
+    root.strip_prefix("<svg")
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|next| next.is_whitespace() || next == '>' || next == '/')
+}
+
+/// Skips the XML declaration, processing instructions, comments and doctype of a document,
+/// returning the text from its root element on, `None` for HTML documents.
+fn root_element(mut text: &str) -> Option<&str> {
+    loop {
+        text = text.trim_start();
+        if text.starts_with("<?") {
+            text = &text[text.find("?>")? + 2..];
+        } else if text.starts_with("<!--") {
+            text = &text[text.find("-->")? + 3..];
+        } else if starts_with_ignore_case(text, "<!doctype") {
+            let name = text[9..].trim_start();
+            if starts_with_ignore_case(name, "html") {
+                return None;
+            }
+            // The doctype may declare an internal subset between brackets.
+            let end = text.find('>')?;
+            let end = match text[..end].find('[') {
+                Some(_) => text.find("]>").map(|end| end + 1)?,
+                None => end,
+            };
+            text = &text[end + 1..];
+        } else if starts_with_ignore_case(text, "<html") {
+            return None;
+        } else {
+            return Some(text);
+        }
+    }
+}
+
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    text.get(..prefix.len())
+        .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+}
+
+/// Walks the JPEG markers up to the first start-of-frame segment.
+fn jpeg_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let mut offset = 2;
+    loop {
+        if *bytes.get(offset)? != 0xFF {
+            return None;
+        }
+        let marker = *bytes.get(offset + 1)?;
+        match marker {
+            // Fill bytes.
+            0xFF => offset += 1,
+            // Markers without a payload.
+            0x01 | 0xD0..=0xD7 => offset += 2,
+            // SOF0 to SOF15, except DHT, JPG and DAC.
+            0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                let height = be_u16(bytes, offset + 5)?;
+                let width = be_u16(bytes, offset + 7)?;
+                return Some((u32::from(width), u32::from(height)));
+            }
+            _ => offset += 2 + usize::from(be_u16(bytes, offset + 2)?),
+        }
+    }
+}
+
+/// Reads the size from the lossy (`VP8 `), lossless (`VP8L`) or extended (`VP8X`) header.
+fn webp_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    match bytes.get(12..16)? {
+        b"VP8 " => Some((
+            u32::from(le_u16(bytes, 26)? & 0x3FFF),
+            u32::from(le_u16(bytes, 28)? & 0x3FFF),
+        )),
+        b"VP8L" => {
+            let b = bytes.get(21..25)?;
+            let (b0, b1, b2, b3) = (
+                u32::from(b[0]),
+                u32::from(b[1]),
+                u32::from(b[2]),
+                u32::from(b[3]),
+            );
+            let width = 1 + (b0 | (b1 & 0x3F) << 8);
+            let height = 1 + (b1 >> 6 | b2 << 2 | (b3 & 0x0F) << 10);
+            Some((width, height))
+        }
+        b"VP8X" => Some((1 + le_u24(bytes, 24)?, 1 + le_u24(bytes, 27)?)),
+        _ => None,
+    }
+}
+
+/// Reads the first image spatial extents (`ispe`) property.
+fn avif_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let position = bytes.windows(4).position(|window| window == b"ispe")?;
+    // Skip the box type, then the version and flags.
+    let offset = position + 8;
+    Some((be_u32(bytes, offset)?, be_u32(bytes, offset + 4)?))
+}
+
+/// Reads the `width` and `height` attributes of the root `<svg>`, or its `viewBox`.
+fn svg_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let text = String::from_utf8_lossy(&bytes[..bytes.len().min(4096)]);
+    let start = text.find("<svg")?;
+    let tag = &text[start..start + text[start..].find('>')?];
+
+    let attribute = |name: &str| -> Option<&str> {
+        let mut rest = tag;
+        loop {
+            let index = rest.find(name)?;
+            let preceded_by_space = rest[..index]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_whitespace);
+            rest = &rest[index + name.len()..];
+            let Some(value) = rest.trim_start().strip_prefix('=') else {
+                continue;
+            };
+            let value = value.trim_start();
+            let quote = value.chars().next()?;
+            if preceded_by_space && (quote == '"' || quote == '\'') {
+                let value = &value[1..];
+                return Some(&value[..value.find(quote)?]);
+            }
+        }
+    };
+    let pixels = |value: &str| -> Option<u32> {
+        let value = value.trim();
+        let value = value.strip_suffix("px").unwrap_or(value);
+        let value = value.parse::<f64>().ok()?;
+        (value.is_finite() && value > 0.0).then(|| value.round() as u32)
+    };
+
+    if let (Some(width), Some(height)) = (
+        attribute("width").and_then(pixels),
+        attribute("height").and_then(pixels),
+    ) {
+        return Some((width, height));
+    }
+
+    let view_box: Vec<&str> = attribute("viewBox")?
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|part| !part.is_empty())
+        .collect();
+    match view_box.as_slice() {
+        [_, _, width, height] => Some((pixels(width)?, pixels(height)?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_png_dimensions() {
+        let mut png = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR".to_vec();
+        png.extend_from_slice(&800u32.to_be_bytes());
+        png.extend_from_slice(&600u32.to_be_bytes());
+        assert_eq!(
+            sniff(Some("image/png"), &png),
+            Some(ImageInfo {
+                format: ImageFormat::Png,
+                dimensions: Some((800, 600)),
+            })
+        );
+    }
+
+    #[test]
+    fn reads_jpeg_dimensions_after_app_segments() {
+        let jpeg = [
+            0xFF, 0xD8, // SOI
+            0xFF, 0xE0, 0x00, 0x04, 0x4A, 0x46, // APP0, 2 bytes of payload
+            0xFF, 0xC2, 0x00, 0x11, 0x08, 0x01, 0xE0, 0x02, 0x80, // SOF2, 640x480
+        ];
+        let info = sniff(Some("image/jpeg; charset=binary"), &jpeg).unwrap();
+        assert_eq!(info.format, ImageFormat::Jpeg);
+        assert_eq!(info.dimensions, Some((640, 480)));
+    }
+
+    #[test]
+    fn reads_webp_dimensions() {
+        let mut lossy = b"RIFF\x00\x00\x00\x00WEBPVP8 \x00\x00\x00\x00".to_vec();
+        lossy.extend_from_slice(&[0; 6]);
+        lossy.extend_from_slice(&400u16.to_le_bytes());
+        lossy.extend_from_slice(&300u16.to_le_bytes());
+        assert_eq!(dimensions(ImageFormat::WebP, &lossy), Some((400, 300)));
+
+        let mut lossless = b"RIFF\x00\x00\x00\x00WEBPVP8L\x00\x00\x00\x00\x2f".to_vec();
+        // 14 bits of width - 1, then 14 bits of height - 1.
+        let bits: u32 = (100 - 1) | ((50 - 1) << 14);
+        lossless.extend_from_slice(&bits.to_le_bytes());
+        assert_eq!(detect_format(&lossless), Some(ImageFormat::WebP));
+        assert_eq!(dimensions(ImageFormat::WebP, &lossless), Some((100, 50)));
+
+        let mut extended = b"RIFF\x00\x00\x00\x00WEBPVP8X\x00\x00\x00\x00".to_vec();
+        extended.extend_from_slice(&[0; 4]);
+        extended.extend_from_slice(&[0x1F, 0x03, 0x00, 0x57, 0x02, 0x00]);
+        assert_eq!(dimensions(ImageFormat::WebP, &extended), Some((800, 600)));
+    }
+
+    #[test]
+    fn reads_avif_dimensions() {
+        let mut avif = b"\x00\x00\x00\x1cftypavif\x00\x00\x00\x00avifmif1miaf".to_vec();
+        avif.extend_from_slice(b"\x00\x00\x00\x14ispe\x00\x00\x00\x00");
+        avif.extend_from_slice(&1920u32.to_be_bytes());
+        avif.extend_from_slice(&1080u32.to_be_bytes());
+        let info = sniff(None, &avif).unwrap();
+        assert_eq!(info.format, ImageFormat::Avif);
+        assert_eq!(info.dimensions, Some((1920, 1080)));
+
+        let heic = b"\x00\x00\x00\x18ftypheic\x00\x00\x00\x00mif1heic";
+        assert_eq!(detect_format(heic), None);
+    }
+
+    #[test]
+    fn reads_svg_dimensions() {
+        let svg = br#"<?xml version="1.0"?>
+            <svg xmlns="http://www.w3.org/2000/svg" stroke-width="2" width="24px" height="16">"#;
+        let info = sniff(Some("image/svg+xml"), svg).unwrap();
+        assert_eq!(info.format, ImageFormat::Svg);
+        assert_eq!(info.dimensions, Some((24, 16)));
+
+        let svg = br#"<svg viewBox="0 0 120 80" width="100%"></svg>"#;
+        assert_eq!(dimensions(ImageFormat::Svg, svg), Some((120, 80)));
+    }
+
+    #[test]
+    fn rejects_html_and_unknown_bytes() {
+        let html = b"<!DOCTYPE html><html><body>Not Found</body></html>";
+        assert_eq!(sniff(Some("text/html; charset=utf-8"), html), None);
+        assert_eq!(sniff(None, html), None);
+        assert_eq!(sniff(Some("image/png"), b"{\"error\": 404}"), None);
+        assert_eq!(sniff(Some("image/png"), b""), None);
+    }
+
+    #[test]
+    fn rejects_html_with_an_inline_svg() {
+        let html =
+            br#"<!DOCTYPE html><html><body><svg viewBox="0 0 24 24"></svg>Not Found</body></html>"#;
+        assert_eq!(sniff(None, html), None);
+        assert_eq!(sniff(Some("application/octet-stream"), html), None);
+        let html =
+            br#"<?xml version="1.0"?><!-- icon --><html><svg width="24" height="24"/></html>"#;
+        assert_eq!(sniff(None, html), None);
+
+        let svg = br#"<?xml version="1.0"?>
+            <!-- icon -->
+            <!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd" [
+                <!ENTITY ns "http://www.w3.org/2000/svg">
+            ]>
+            <svg width="24" height="24"/>"#;
+        assert_eq!(detect_format(svg), Some(ImageFormat::Svg));
+        assert_eq!(detect_format(b"<svg/>"), Some(ImageFormat::Svg));
+        assert_eq!(detect_format(b"<svgz/>"), None);
+    }
+
+    #[test]
+    fn truncated_headers_do_not_panic() {
+        let png = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR\x00\x00";
+        assert_eq!(
+            sniff(None, png).map(|info| info.dimensions),
+            Some(None),
+            "format without dimensions"
+        );
+        assert_eq!(
+            dimensions(ImageFormat::Jpeg, &[0xFF, 0xD8, 0xFF, 0xE0]),
+            None
+        );
+        assert_eq!(dimensions(ImageFormat::WebP, b"RIFF\0\0\0\0WEBPVP8L"), None);
+    }
+}
@@ -0,0 +1,338 @@
+use super::Layout;
+use super::loader::{DEFAULT_LOADER_WIDTH, Loader, LoaderProps, is_unoptimizable};
+
+/// Widths used to generate the `srcset` of an image.
+///
+/// `device_sizes` are the viewport widths of the devices you expect to serve, used for images
+/// that scale with their container. `image_sizes` are smaller widths used for images rendered
+/// at a fraction of the viewport or at a fixed size. Both default to the values used by Next.js.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakpoints {
+    /// Device widths, in pixels.
+    pub device_sizes: Vec<u32>,
+
+    /// Image widths, in pixels.
+    pub image_sizes: Vec<u32>,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Breakpoints {
+            device_sizes: vec![640, 750, 828, 1080, 1200, 1920, 2048, 3840],
+            image_sizes: vec![16, 32, 48, 64, 96, 128, 256, 384],
+        }
+    }
+}
+
+impl Breakpoints {
+    /// Returns the device and image widths merged, sorted and deduplicated.
+    pub fn all_sizes(&self) -> Vec<u32> {
+        let mut sizes: Vec<u32> = self
+            .image_sizes
+            .iter()
+            .chain(&self.device_sizes)
+            .copied()
+            .collect();
+        sizes.sort_unstable();
+        sizes.dedup();
+        sizes
+    }
+
+    /// Returns the device widths sorted and deduplicated.
+    fn sorted_device_sizes(&self) -> Vec<u32> {
+        let mut sizes = self.device_sizes.clone();
+        sizes.sort_unstable();
+        sizes.dedup();
+        sizes
+    }
+}
+
+/// Descriptor appended to each candidate of a `srcset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Descriptor {
+    /// Width descriptors (`640w`), used for images that scale with their container.
+    Width,
+
+    /// Pixel density descriptors (`1x`, `2x`), used for images rendered at a known size.
+    Density,
+}
+
+/// The `src`, `srcset` and `sizes` attributes generated for an `<img>`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ImgAttrs {
+    /// The fallback `src`, pointing to the largest candidate.
+    pub src: String,
+
+    /// The generated `srcset`, empty when no loader applies.
+    pub srcset: String,
+
+    /// The `sizes` attribute, defaulting to `100vw` for width descriptors.
+    pub sizes: String,
+}
+
+/// Returns `true` when the image scales with its container rather than its `width`.
+fn is_fluid(layout: Layout) -> bool {
+    matches!(
+        layout,
+        Layout::Responsive | Layout::Fill | Layout::Stretch | Layout::AspectRatio
+    )
+}
+
+/// Extracts the viewport percentages (`50vw` -> `50`) used in a `sizes` attribute.
+fn viewport_percentages(sizes: &str) -> Vec<u32> {
+    sizes
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '(' || c == ')')
+        .filter_map(|token| token.strip_suffix("vw"))
+        .filter(|value| (1..=3).contains(&value.len()))
+        .filter_map(|value| value.parse::<u32>().ok())
+        .filter(|value| *value < 200)
+        .collect()
+}
+
+/// Picks the candidate widths and descriptor kind for an image.
+///
+/// Follows the rules of the Next.js `Image` component:
+/// - fluid layouts with `sizes` use every width large enough for the smallest `vw` in `sizes`;
+/// - fluid layouts, or images without a known `width`, use the device widths;
+/// - everything else uses the closest widths to `width` at 1x and 2x densities.
+pub fn candidate_widths(
+    breakpoints: &Breakpoints,
+    width: Option<u32>,
+    layout: Layout,
+    sizes: &str,
+) -> (Vec<u32>, Descriptor) {
+    let all_sizes = breakpoints.all_sizes();
+
+    if !sizes.is_empty() && is_fluid(layout) {
+        let percentages = viewport_percentages(sizes);
+        let widths = match (
+            percentages.iter().min(),
+            breakpoints.device_sizes.iter().min(),
+        ) {
+            (Some(smallest), Some(smallest_device)) => {
+                let threshold = f64::from(*smallest_device) * f64::from(*smallest) * 0.01;
+                all_sizes
+                    .into_iter()
+                    .filter(|size| f64::from(*size) >= threshold)
+                    .collect()
+            }
+            _ => all_sizes,
+        };
+        return (widths, Descriptor::Width);
+    }
+
+    match width {
+        Some(width) if !is_fluid(layout) => {
+            let mut widths: Vec<u32> = [width, width.saturating_mul(2)]
+                .iter()
+                .filter_map(|target| {
+                    all_sizes
+                        .iter()
+                        .find(|size| *size >= target)
+                        .or(all_sizes.last())
+                        .copied()
+                })
+                .collect();
+            widths.dedup();
+            (widths, Descriptor::Density)
+        }
+        _ => (breakpoints.sorted_device_sizes(), Descriptor::Width),
+    }
+}
+
+/// Generates the `src`, `srcset` and `sizes` attributes of an image.
+///
+/// Every candidate width is passed to `loader` along with the other fields of `props`, whose
+/// own `width` only scales `height` to each candidate (see [`LoaderProps::with_width`]). When
+/// no loader applies (see [`resolve_src`](super::loader::resolve_src)), `src` and `sizes` are
+/// returned unchanged and `srcset` is empty.
+///
+/// # Example
+/// ```rust
+/// use image_rs::common::srcset::generate_img_attrs;
+/// use image_rs::{Breakpoints, Layout, Loader, LoaderProps};
+///
+/// let loader = Loader::new(|props: LoaderProps<'_>| format!("{}?w={}", props.src, props.width));
+/// let attrs = generate_img_attrs(
+///     LoaderProps { src: "/logo.png", ..Default::default() },
+///     Some(48),
+///     Layout::Fixed,
+///     "",
+///     &Breakpoints::default(),
+///     Some(&loader),
+///     false,
+/// );
+/// assert_eq!(attrs.srcset, "/logo.png?w=48 1x, /logo.png?w=96 2x");
+/// assert_eq!(attrs.src, "/logo.png?w=96");
+/// ```
+pub fn generate_img_attrs(
+    props: LoaderProps<'_>,
+    width: Option<u32>,
+    layout: Layout,
+    sizes: &str,
+    breakpoints: &Breakpoints,
+    loader: Option<&Loader>,
+    unoptimized: bool,
+) -> ImgAttrs {
+    let loader = match loader {
+        Some(loader) if !unoptimized && !is_unoptimizable(props.src) => loader,
+        _ => {
+            return ImgAttrs {
+                src: props.src.to_string(),
+                srcset: String::new(),
+                sizes: sizes.to_string(),
+            };
+        }
+    };
+
+    let (widths, descriptor) = candidate_widths(breakpoints, width, layout, sizes);
+    let url = |width: u32| loader.url(props.with_width(width));
+
+    let srcset = widths
+        .iter()
+        .enumerate()
+        .map(|(index, width)| match descriptor {
+            Descriptor::Width => format!("{} {}w", url(*width), width),
+            Descriptor::Density => format!("{} {}x", url(*width), index + 1),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let src = match widths.last() {
+        Some(largest) => url(*largest),
+        None => url(width.unwrap_or(DEFAULT_LOADER_WIDTH)),
+    };
+
+    let sizes = if sizes.is_empty() && descriptor == Descriptor::Width {
+        "100vw".to_string()
+    } else {
+        sizes.to_string()
+    };
+
+    ImgAttrs { src, srcset, sizes }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loader() -> Loader {
+        Loader::new(|props: LoaderProps<'_>| format!("{}?w={}", props.src, props.width))
+    }
+
+    fn device_sizes() -> Vec<u32> {
+        Breakpoints::default().device_sizes
+    }
+
+    #[test]
+    fn sizes_drop_widths_below_the_smallest_viewport_share() {
+        let sizes = "(max-width: 768px) 50vw, 33vw";
+        assert_eq!(viewport_percentages(sizes), vec![50, 33]);
+
+        // 33% of the smallest device (640px) is 211px.
+        let (widths, descriptor) = candidate_widths(
+            &Breakpoints::default(),
+            Some(800),
+            Layout::Responsive,
+            sizes,
+        );
+        assert_eq!(descriptor, Descriptor::Width);
+        assert_eq!(
+            widths,
+            vec![256, 384, 640, 750, 828, 1080, 1200, 1920, 2048, 3840]
+        );
+
+        let (widths, _) = candidate_widths(
+            &Breakpoints::default(),
+            None,
+            Layout::Fill,
+            "(max-width: 768px) 100vw",
+        );
+        assert_eq!(widths, device_sizes());
+    }
+
+    #[test]
+    fn fluid_layouts_without_sizes_use_device_widths() {
+        let (widths, descriptor) =
+            candidate_widths(&Breakpoints::default(), Some(800), Layout::Responsive, "");
+        assert_eq!((widths, descriptor), (device_sizes(), Descriptor::Width));
+
+        let attrs = generate_img_attrs(
+            LoaderProps {
+                src: "/hero.jpg",
+                ..Default::default()
+            },
+            Some(800),
+            Layout::Responsive,
+            "",
+            &Breakpoints::default(),
+            Some(&loader()),
+            false,
+        );
+        assert_eq!(attrs.sizes, "100vw");
+        assert!(
+            attrs
+                .srcset
+                .starts_with("/hero.jpg?w=640 640w, /hero.jpg?w=750 750w")
+        );
+        assert_eq!(attrs.src, "/hero.jpg?w=3840");
+    }
+
+    #[test]
+    fn densities_are_clamped_to_the_largest_breakpoint() {
+        let (widths, descriptor) =
+            candidate_widths(&Breakpoints::default(), Some(3000), Layout::Fixed, "");
+        assert_eq!((widths, descriptor), (vec![3840], Descriptor::Density));
+
+        let (widths, _) =
+            candidate_widths(&Breakpoints::default(), Some(1500), Layout::Intrinsic, "");
+        assert_eq!(widths, vec![1920, 3840]);
+
+        let attrs = generate_img_attrs(
+            LoaderProps {
+                src: "/hero.jpg",
+                ..Default::default()
+            },
+            Some(3000),
+            Layout::Fixed,
+            "",
+            &Breakpoints::default(),
+            Some(&loader()),
+            false,
+        );
+        assert_eq!(attrs.srcset, "/hero.jpg?w=3840 1x");
+        assert_eq!(attrs.sizes, "");
+    }
+
+    #[test]
+    fn candidates_keep_the_aspect_ratio() {
+        let loader = Loader::new(|props: LoaderProps<'_>| {
+            format!("{}?w={}&h={:?}", props.src, props.width, props.height)
+        });
+        let attrs = generate_img_attrs(
+            LoaderProps {
+                src: "/hero.jpg",
+                width: 400,
+                height: Some(300),
+                ..Default::default()
+            },
+            Some(400),
+            Layout::Fixed,
+            "",
+            &Breakpoints::default(),
+            Some(&loader),
+            false,
+        );
+        assert_eq!(
+            attrs.srcset,
+            "/hero.jpg?w=640&h=Some(480) 1x, /hero.jpg?w=828&h=Some(621) 2x"
+        );
+    }
+
+    #[test]
+    fn fixed_layouts_without_width_use_device_widths() {
+        let (widths, descriptor) =
+            candidate_widths(&Breakpoints::default(), None, Layout::Fixed, "");
+        assert_eq!((widths, descriptor), (device_sizes(), Descriptor::Width));
+    }
+}
@@ -0,0 +1,65 @@
+use super::placeholder::Placeholder;
+
+/// An image bundled with the app, whose size and placeholder are known at compile time.
+///
+/// Usually built by the `static_image!` macro of the `image-rs-macros` crate, which reads the
+/// file when compiling:
+///
+/// ```rust,ignore
+/// use image_rs_macros::static_image;
+///
+/// const HERO: StaticImage = static_image!("assets/hero.jpg");
+/// ```
+///
+/// Passed to the `static_image` prop of an `Image`, it provides the `src`, the `width` and
+/// `height` and a blur placeholder, unless they are set explicitly. A `Layout::Responsive`
+/// image then always reserves its exact space, avoiding layout shifts.
+///
+/// # Example
+/// ```rust
+/// use image_rs::{Placeholder, StaticImage};
+///
+/// let hero = StaticImage::new("/assets/hero.jpg", 1200, 800);
+/// assert_eq!(hero.placeholder::<()>(), Placeholder::Empty);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StaticImage {
+    /// The public URL of the image.
+    pub src: &'static str,
+
+    /// Intrinsic width of the image, in pixels.
+    pub width: u32,
+
+    /// Intrinsic height of the image, in pixels.
+    pub height: u32,
+
+    /// A tiny blurred version of the image as a data URL, empty for none.
+    pub blur_data_url: &'static str,
+}
+
+impl StaticImage {
+    /// Creates a static image without placeholder.
+    pub const fn new(src: &'static str, width: u32, height: u32) -> Self {
+        StaticImage {
+            src,
+            width,
+            height,
+            blur_data_url: "",
+        }
+    }
+
+    /// Sets the data URL of the blur placeholder.
+    pub const fn with_blur_data_url(mut self, blur_data_url: &'static str) -> Self {
+        self.blur_data_url = blur_data_url;
+        self
+    }
+
+    /// Returns the [`Placeholder::Blur`] of the image, [`Placeholder::Empty`] without one.
+    pub fn placeholder<V>(&self) -> Placeholder<V> {
+        if self.blur_data_url.is_empty() {
+            Placeholder::Empty
+        } else {
+            Placeholder::Blur(self.blur_data_url.to_string())
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use super::core::{ImageAttributes, ImageEvent, ImageOptions, ImageState, LoadEvent};
+use super::decode::decode_image;
+use super::fallback::{ImageSource, fallback_chain, resolve_fallback};
+use super::placeholder::Placeholder;
+use super::transition::Transition;
+use super::{
+    Breakpoints, CrossOrigin, Decoding, Dimension, FetchPriority, ImageError, Layout, Loader,
+    Loading, ObjectFit, Position, ReferrerPolicy,
+};
+
+/// Options of the `use_image` hooks, matching the props of an `Image` with the same name.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UseImageOptions {
+    /// The width forwarded to the `loader`, in pixels.
+    pub width: Dimension,
+
+    /// The quality forwarded to the `loader`.
+    pub quality: String,
+
+    /// The `sizes` used to pick a `srcset` candidate.
+    pub sizes: String,
+
+    /// A hand-written `srcset`, taking precedence over the generated one.
+    pub srcset: String,
+
+    /// The layout the image will be rendered with, which decides the `srcset` descriptors.
+    pub layout: Layout,
+
+    /// Builds the final image URL from `src`, `width` and `quality`.
+    pub loader: Option<Loader>,
+
+    /// The widths used to generate the `srcset` when a `loader` is set.
+    pub breakpoints: Breakpoints,
+
+    /// Skips the `loader` and uses `src` as is.
+    pub unoptimized: bool,
+
+    /// The first fallback image, tried when `src` fails.
+    pub fallback_src: String,
+
+    /// Additional fallback images, tried in order after `fallback_src`.
+    pub fallbacks: Vec<ImageSource>,
+
+    /// CORS policy for fetching the image.
+    pub crossorigin: CrossOrigin,
+
+    /// Referrer policy when fetching the image.
+    pub referrerpolicy: ReferrerPolicy,
+}
+
+impl UseImageOptions {
+    /// Computes the attributes an `Image` with these options would render for `src`.
+    pub fn attributes(&self, src: &str) -> ImageAttributes {
+        ImageOptions {
+            src,
+            width: self.width,
+            height: Dimension::Auto,
+            quality: &self.quality,
+            sizes: &self.sizes,
+            srcset: &self.srcset,
+            sources: &[],
+            style: "",
+            placeholder: &Placeholder::<()>::Empty,
+            static_image: None,
+            aspect_ratio: None,
+            layout: self.layout,
+            object_fit: ObjectFit::default(),
+            object_position: Position::default(),
+            loading: Loading::Eager,
+            fetchpriority: FetchPriority::default(),
+            decoding: Decoding::default(),
+            transition: Transition::default(),
+            reduced_motion: false,
+            priority: false,
+            loader: self.loader.as_ref(),
+            breakpoints: &self.breakpoints,
+            unoptimized: self.unoptimized,
+        }
+        .attributes()
+    }
+}
+
+/// Load status of an image, as returned by the `use_image` hooks.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ImageStatus {
+    /// Where the image is in its lifecycle.
+    pub state: ImageState,
+
+    /// The URL being loaded or displayed: the resolved `src`, the `srcset` candidate picked by
+    /// the browser once decoded, or the fallback replacing it.
+    pub url: Option<String>,
+
+    /// The index of the fallback replacing the image in the chain, if any.
+    pub fallback: Option<usize>,
+
+    /// The intrinsic width and height of the decoded image, in pixels.
+    pub natural_size: Option<(u32, u32)>,
+
+    /// Why the image, or the last fallback tried, failed.
+    pub error: Option<ImageError>,
+}
+
+impl ImageStatus {
+    /// Returns `true` until the image is decoded or failed for good.
+    pub fn is_loading(&self) -> bool {
+        self.url.is_some() && !self.state.is_settled()
+    }
+
+    /// Returns `true` once the image, or a fallback, is decoded.
+    pub fn is_loaded(&self) -> bool {
+        self.state == ImageState::Decoded
+    }
+
+    /// Returns `true` once the image and all of its fallbacks failed.
+    pub fn is_failed(&self) -> bool {
+        self.state == ImageState::Failed
+    }
+
+    /// Returns the status after `event`.
+    fn next(mut self, event: ImageEvent) -> Self {
+        self.state = self.state.next(event);
+        self
+    }
+
+    fn decoded(self, load: LoadEvent) -> Self {
+        ImageStatus {
+            url: Some(load.url),
+            fallback: load.fallback,
+            natural_size: Some((load.natural_width, load.natural_height)),
+            error: None,
+            ..self.next(ImageEvent::Load)
+        }
+    }
+}
+
+/// Loads `src` off-DOM with the same loader, state machine and fallback chain as an `Image`,
+/// reporting every change of status to `on_status`.
+///
+/// Stops without reporting anything else once `is_cancelled` returns `true`, e.g. when `src`
+/// changed or the component unmounted.
+pub async fn load_image(
+    src: &str,
+    options: &UseImageOptions,
+    is_cancelled: impl Fn() -> bool,
+    on_status: impl Fn(ImageStatus),
+) {
+    let attrs = options.attributes(src);
+    if attrs.src.is_empty() && attrs.srcset.is_empty() {
+        return on_status(ImageStatus::default());
+    }
+    let status = ImageStatus {
+        state: ImageState::initial(false),
+        url: Some(attrs.src.clone()),
+        ..ImageStatus::default()
+    };
+    on_status(status.clone());
+
+    let (crossorigin, referrerpolicy) = (&options.crossorigin, options.referrerpolicy);
+    let result = decode_image(
+        &attrs.src,
+        &attrs.srcset,
+        &attrs.sizes,
+        crossorigin,
+        referrerpolicy,
+    )
+    .await;
+    if is_cancelled() {
+        return;
+    }
+    let error = match result {
+        Ok(load) => return on_status(status.decoded(load)),
+        Err(error) => error,
+    };
+
+    let chain = fallback_chain(&options.fallback_src, &options.fallbacks);
+    let has_fallback = !chain.is_empty();
+    let status = ImageStatus {
+        error: Some(error),
+        ..status.next(ImageEvent::Error { has_fallback })
+    };
+    on_status(status.clone());
+    if !has_fallback {
+        return;
+    }
+
+    let result = match resolve_fallback(&chain, 0, &is_cancelled, |_| {}).await {
+        Ok((index, _)) => {
+            let url = &chain[index].src;
+            decode_image(url, "", "", crossorigin, referrerpolicy)
+                .await
+                .map(|load| LoadEvent {
+                    fallback: Some(index),
+                    ..load
+                })
+                .map_err(|_| ImageError::Fallback { url: url.clone() })
+        }
+        Err(error) => Err(error),
+    };
+    if is_cancelled() || matches!(result, Err(ImageError::Aborted { .. })) {
+        return;
+    }
+    on_status(match result {
+        Ok(load) => status.decoded(load),
+        Err(error) => ImageStatus {
+            error: Some(error),
+            ..status.next(ImageEvent::Error {
+                has_fallback: false,
+            })
+        },
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attributes_match_the_image() {
+        let options = UseImageOptions {
+            sizes: "50vw".into(),
+            srcset: "/a.jpg 1x, /a@2x.jpg 2x".into(),
+            ..UseImageOptions::default()
+        };
+        let attrs = options.attributes("/a.jpg");
+        assert_eq!(attrs.src, "/a.jpg");
+        assert_eq!(attrs.srcset, "/a.jpg 1x, /a@2x.jpg 2x");
+        assert_eq!(attrs.sizes, "50vw");
+    }
+
+    #[test]
+    fn status_follows_the_state_machine() {
+        let status = ImageStatus {
+            state: ImageState::Loading,
+            url: Some("/a.jpg".into()),
+            ..ImageStatus::default()
+        };
+        assert!(status.is_loading());
+
+        let decoded = status.clone().decoded(LoadEvent {
+            url: "/a@2x.jpg".into(),
+            fallback: None,
+            natural_width: 1600,
+            natural_height: 900,
+        });
+        assert!(decoded.is_loaded() && !decoded.is_loading());
+        assert_eq!(decoded.url.as_deref(), Some("/a@2x.jpg"));
+        assert_eq!(decoded.natural_size, Some((1600, 900)));
+
+        let failed = status.next(ImageEvent::Error {
+            has_fallback: false,
+        });
+        assert!(failed.is_failed());
+        assert!(!ImageStatus::default().is_loading());
+    }
+}
@@ -0,0 +1,336 @@
+//! A pure-Rust [ThumbHash](https://evanw.github.io/thumbhash/) decoder.
+//!
+//! Unlike a BlurHash, a ThumbHash keeps the alpha channel and the approximate aspect ratio of
+//! the image, so transparent logos keep their shape and responsive images can reserve their
+//! space before `width` and `height` are known.
+
+use std::f64::consts::PI;
+use std::fmt;
+
+/// Error returned when a ThumbHash cannot be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThumbHashError {
+    /// The hash is shorter than its header announces.
+    Length {
+        /// The minimum number of bytes.
+        expected: usize,
+        /// The actual number of bytes.
+        actual: usize,
+    },
+
+    /// The base64 string contains a character outside of the alphabet.
+    Base64 {
+        /// The index of the character in the string.
+        index: usize,
+    },
+}
+
+impl fmt::Display for ThumbHashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThumbHashError::Length { expected, actual } => {
+                write!(f, "invalid ThumbHash length {actual}, expected {expected}")
+            }
+            ThumbHashError::Base64 { index } => {
+                write!(f, "invalid base64 character at index {index}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThumbHashError {}
+
+/// Decodes a base64 ThumbHash, with the standard or URL-safe alphabet, padded or not.
+///
+/// # Errors
+/// Returns [`ThumbHashError::Base64`] on a character outside of the alphabet.
+pub fn from_base64(hash: &str) -> Result<Vec<u8>, ThumbHashError> {
+    let mut bytes = Vec::with_capacity(hash.len() * 3 / 4);
+    let (mut buffer, mut bits) = (0u32, 0);
+    for (index, char) in hash.trim_end_matches('=').bytes().enumerate() {
+        let value = match char {
+            b'A'..=b'Z' => char - b'A',
+            b'a'..=b'z' => char - b'a' + 26,
+            b'0'..=b'9' => char - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return Err(ThumbHashError::Base64 { index }),
+        };
+        buffer = buffer << 6 | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Ok(bytes)
+}
+
+/// The constants stored in the first bytes of a ThumbHash.
+struct Header {
+    l_dc: f64,
+    p_dc: f64,
+    q_dc: f64,
+    l_scale: f64,
+    p_scale: f64,
+    q_scale: f64,
+    has_alpha: bool,
+    is_landscape: bool,
+    /// Number of horizontal and vertical luminance components.
+    lx: usize,
+    ly: usize,
+}
+
+impl Header {
+    fn read(hash: &[u8]) -> Result<Self, ThumbHashError> {
+        let has_alpha = hash.get(2).is_some_and(|byte| byte & 0x80 != 0);
+        let expected = if has_alpha { 6 } else { 5 };
+        if hash.len() < expected {
+            return Err(ThumbHashError::Length {
+                expected,
+                actual: hash.len(),
+            });
+        }
+        let header24 = u32::from(hash[0]) | u32::from(hash[1]) << 8 | u32::from(hash[2]) << 16;
+        let header16 = u16::from(hash[3]) | u16::from(hash[4]) << 8;
+        let is_landscape = header16 >> 15 != 0;
+        let (lx, ly) = match (is_landscape, has_alpha) {
+            (true, true) => (5, header16 & 7),
+            (true, false) => (7, header16 & 7),
+            (false, true) => (header16 & 7, 5),
+            (false, false) => (header16 & 7, 7),
+        };
+        Ok(Header {
+            l_dc: f64::from(header24 & 63) / 63.0,
+            p_dc: f64::from((header24 >> 6) & 63) / 31.5 - 1.0,
+            q_dc: f64::from((header24 >> 12) & 63) / 31.5 - 1.0,
+            l_scale: f64::from((header24 >> 18) & 31) / 31.0,
+            p_scale: f64::from((header16 >> 3) & 63) / 63.0,
+            q_scale: f64::from((header16 >> 9) & 63) / 63.0,
+            has_alpha,
+            is_landscape,
+            lx: usize::from(lx.max(3)),
+            ly: usize::from(ly.max(3)),
+        })
+    }
+}
+
+/// Returns the approximate aspect ratio, width over height, of the image of `hash`.
+pub fn aspect_ratio(hash: &[u8]) -> Result<f64, ThumbHashError> {
+    let header = Header::read(hash)?;
+    let components = usize::from(hash[3] & 7) as f64;
+    let long = if header.has_alpha { 5.0 } else { 7.0 };
+    Ok(if header.is_landscape {
+        long / components
+    } else {
+        components / long
+    })
+}
+
+/// Returns the average color of the image of `hash`, as RGBA.
+pub fn average_color(hash: &[u8]) -> Result<[u8; 4], ThumbHashError> {
+    let header = Header::read(hash)?;
+    let a = if header.has_alpha {
+        f64::from(hash[5] & 15) / 15.0
+    } else {
+        1.0
+    };
+    let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let [r, g, b] = rgb(header.l_dc, header.p_dc, header.q_dc);
+    Ok([channel(r), channel(g), channel(b), channel(a)])
+}
+
+/// Converts the LPQ color space of ThumbHash to RGB.
+fn rgb(l: f64, p: f64, q: f64) -> [f64; 3] {
+    let b = l - 2.0 / 3.0 * p;
+    let r = (3.0 * l - b + q) / 2.0;
+    [r, r - q, b]
+}
+
+/// Decodes `hash` into an RGBA buffer, row by row, returning it with its width and height.
+///
+/// The image is at most 32 pixels wide and high, with the aspect ratio of the hash.
+///
+/// # Errors
+/// Returns [`ThumbHashError::Length`] if `hash` is truncated.
+pub fn decode(hash: &[u8]) -> Result<(Vec<u8>, u32, u32), ThumbHashError> {
+    let header = Header::read(hash)?;
+    let has_alpha = header.has_alpha;
+    let (a_dc, a_scale) = if has_alpha {
+        (
+            f64::from(hash[5] & 15) / 15.0,
+            f64::from(hash[5] >> 4) / 15.0,
+        )
+    } else {
+        (1.0, 0.0)
+    };
+
+    // The factors are packed as nibbles after the header. Saturation is boosted by 1.25x to
+    // compensate for the quantization, as in the reference implementation.
+    let ac_start = if has_alpha { 6 } else { 5 };
+    let mut ac_index = 0;
+    let mut channel = |nx: usize, ny: usize, scale: f64| {
+        let mut ac = Vec::new();
+        for cy in 0..ny {
+            let mut cx = usize::from(cy == 0);
+            while cx * ny < nx * (ny - cy) {
+                let index = ac_start + (ac_index >> 1);
+                let byte = *hash.get(index).ok_or(ThumbHashError::Length {
+                    expected: index + 1,
+                    actual: hash.len(),
+                })?;
+                let nibble = (byte >> ((ac_index & 1) << 2)) & 15;
+                ac.push((f64::from(nibble) / 7.5 - 1.0) * scale);
+                ac_index += 1;
+                cx += 1;
+            }
+        }
+        Ok(ac)
+    };
+    let l_ac = channel(header.lx, header.ly, header.l_scale)?;
+    let p_ac = channel(3, 3, header.p_scale * 1.25)?;
+    let q_ac = channel(3, 3, header.q_scale * 1.25)?;
+    let a_ac = if has_alpha {
+        channel(5, 5, a_scale)?
+    } else {
+        Vec::new()
+    };
+
+    let ratio = aspect_ratio(hash)?;
+    let (width, height) = if ratio > 1.0 {
+        (32, (32.0 / ratio).round() as u32)
+    } else {
+        ((32.0 * ratio).round() as u32, 32)
+    };
+    let (lx, ly) = (header.lx, header.ly);
+    let fx_len = lx.max(if has_alpha { 5 } else { 3 });
+    let fy_len = ly.max(if has_alpha { 5 } else { 3 });
+
+    // The cosine factors only depend on the column or the row of the pixel.
+    let columns: Vec<Vec<f64>> = (0..width).map(|x| cosines(fx_len, width, x)).collect();
+
+    let mut rgba = Vec::with_capacity(width as usize * height as usize * 4);
+    for y in 0..height {
+        let fy = cosines(fy_len, height, y);
+        for fx in &columns {
+            // Sums the factors of a channel of `nx` x `ny` components over the triangle
+            // they are stored in.
+            let sum = |ac: &[f64], nx: usize, ny: usize| {
+                let mut value = 0.0;
+                let mut j = 0;
+                for (cy, fy) in fy.iter().enumerate().take(ny) {
+                    let fy2 = fy * 2.0;
+                    let mut cx = usize::from(cy == 0);
+                    while cx * ny < nx * (ny - cy) {
+                        value += ac[j] * fx[cx] * fy2;
+                        cx += 1;
+                        j += 1;
+                    }
+                }
+                value
+            };
+
+            let l = header.l_dc + sum(&l_ac, lx, ly);
+            let p = header.p_dc + sum(&p_ac, 3, 3);
+            let q = header.q_dc + sum(&q_ac, 3, 3);
+            let a = if has_alpha {
+                a_dc + sum(&a_ac, 5, 5)
+            } else {
+                a_dc
+            };
+            let [r, g, b] = rgb(l, p, q);
+            rgba.extend([r, g, b, a].map(|value| (255.0 * value.min(1.0)).max(0.0) as u8));
+        }
+    }
+    Ok((rgba, width, height))
+}
+
+/// Returns the `len` cosine factors of the pixel at `index` along an axis of `size` pixels.
+fn cosines(len: usize, size: u32, index: u32) -> Vec<f64> {
+    (0..len)
+        .map(|c| (PI / f64::from(size) * (f64::from(index) + 0.5) * c as f64).cos())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_base64() {
+        assert_eq!(from_base64("AQID"), Ok(vec![1, 2, 3]));
+        assert_eq!(from_base64("AQI="), Ok(vec![1, 2]));
+        assert_eq!(from_base64("-_8"), from_base64("+/8="));
+        assert_eq!(
+            from_base64("AQ!D"),
+            Err(ThumbHashError::Base64 { index: 2 })
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_hashes() {
+        assert_eq!(
+            decode(&[1, 2, 3]),
+            Err(ThumbHashError::Length {
+                expected: 5,
+                actual: 3
+            })
+        );
+        let hash = from_base64("1QcSHQRnh493V4dIh4eXh1h4kJUI").unwrap();
+        assert!(matches!(
+            decode(&hash[..10]),
+            Err(ThumbHashError::Length { .. })
+        ));
+    }
+
+    #[test]
+    fn decodes_reference_hashes() {
+        // Hashes of the reference implementation, with their aspect ratio, size and the
+        // pixels it decodes at the top left, center and bottom right.
+        let references = [
+            (
+                "1QcSHQRnh493V4dIh4eXh1h4kJUI",
+                5.0 / 7.0,
+                (23, 32),
+                [[64, 77, 113, 255], [140, 109, 88, 255], [0, 4, 39, 255]],
+            ),
+            (
+                "YJqGPQw7sFlslqhFafSE+Q6oJ1h2iHB2Rw",
+                1.0,
+                (32, 32),
+                [[228, 75, 51, 0], [107, 102, 109, 255], [255, 136, 0, 0]],
+            ),
+        ];
+        for (hash, ratio, (width, height), expected) in references {
+            let hash = from_base64(hash).unwrap();
+            assert_eq!(aspect_ratio(&hash), Ok(ratio));
+
+            let (pixels, w, h) = decode(&hash).unwrap();
+            assert_eq!((w, h), (width, height));
+            assert_eq!(pixels.len(), (w * h * 4) as usize);
+            let pixel = |x: u32, y: u32| {
+                let start = ((y * w + x) * 4) as usize;
+                [
+                    pixels[start],
+                    pixels[start + 1],
+                    pixels[start + 2],
+                    pixels[start + 3],
+                ]
+            };
+            assert_eq!(
+                [pixel(0, 0), pixel(w / 2, h / 2), pixel(w - 1, h - 1)],
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn average_color_keeps_alpha() {
+        assert_eq!(
+            average_color(&[0x3f, 0x08, 0x02, 0, 0]),
+            Ok([255, 254, 252, 255])
+        );
+        let transparent = from_base64("YJqGPQw7sFlslqhFafSE+Q6oJ1h2iHB2Rw").unwrap();
+        assert!(average_color(&transparent).unwrap()[3] < 255);
+    }
+}
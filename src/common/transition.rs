@@ -0,0 +1,160 @@
+/// How the image replaces its placeholder once decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransitionKind {
+    /// The image appears as soon as it is decoded (default).
+    #[default]
+    None,
+
+    /// The image fades in over the placeholder, which is removed once the image is opaque.
+    Fade,
+
+    /// The image fades in while the placeholder layer fades out.
+    Crossfade,
+
+    /// The blurred image sharpens into the final one.
+    Blur,
+}
+
+/// Timing function of a [`Transition`], mirroring the CSS keywords.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Slow start, fast middle and slow end.
+    Ease,
+
+    /// Slow start.
+    EaseIn,
+
+    /// Slow end (default).
+    #[default]
+    EaseOut,
+
+    /// Slow start and end.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Returns the CSS `transition-timing-function` of the easing.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Easing::Linear => "linear",
+            Easing::Ease => "ease",
+            Easing::EaseIn => "ease-in",
+            Easing::EaseOut => "ease-out",
+            Easing::EaseInOut => "ease-in-out",
+        }
+    }
+}
+
+/// The transition between the placeholder and the decoded image.
+///
+/// Transitions are skipped when the user asked for reduced motion through
+/// `prefers-reduced-motion`.
+///
+/// # Example
+/// ```rust
+/// use image_rs::common::transition::{Easing, Transition, TransitionKind};
+///
+/// let transition = Transition::crossfade()
+///     .with_duration(500)
+///     .with_easing(Easing::EaseInOut);
+/// assert_eq!(transition.kind, TransitionKind::Crossfade);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transition {
+    /// How the image replaces its placeholder.
+    pub kind: TransitionKind,
+
+    /// Duration of the transition, in milliseconds.
+    pub duration_ms: u32,
+
+    /// Timing function of the transition.
+    pub easing: Easing,
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Transition::new(TransitionKind::None)
+    }
+}
+
+impl Transition {
+    /// Creates a transition of `kind`, lasting 300ms with an `ease-out` timing function.
+    pub fn new(kind: TransitionKind) -> Self {
+        Transition {
+            kind,
+            duration_ms: 300,
+            easing: Easing::default(),
+        }
+    }
+
+    /// The image appears as soon as it is decoded.
+    pub fn none() -> Self {
+        Transition::new(TransitionKind::None)
+    }
+
+    /// The image fades in over the placeholder.
+    pub fn fade() -> Self {
+        Transition::new(TransitionKind::Fade)
+    }
+
+    /// The image fades in while the placeholder fades out.
+    pub fn crossfade() -> Self {
+        Transition::new(TransitionKind::Crossfade)
+    }
+
+    /// The blurred image sharpens into the final one.
+    pub fn blur() -> Self {
+        Transition::new(TransitionKind::Blur)
+    }
+
+    /// Sets the duration, in milliseconds.
+    pub fn with_duration(mut self, duration_ms: u32) -> Self {
+        self.duration_ms = duration_ms;
+        self
+    }
+
+    /// Sets the timing function.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the transition actually played: none when motion is reduced or the duration is
+    /// zero, since the `transitionend` event would then never fire.
+    pub fn effective(self, reduced_motion: bool) -> Self {
+        if reduced_motion || self.duration_ms == 0 {
+            Transition::none()
+        } else {
+            self
+        }
+    }
+
+    /// Returns `true` for fades, which hide the image until it is decoded.
+    pub fn fades(&self) -> bool {
+        matches!(self.kind, TransitionKind::Fade | TransitionKind::Crossfade)
+    }
+
+    /// Returns the CSS `transition` of `property`.
+    pub(crate) fn css(&self, property: &str) -> String {
+        format!(
+            "transition: {property} {}ms {};",
+            self.duration_ms,
+            self.easing.as_str()
+        )
+    }
+}
+
+/// Returns `true` if the user asked the system to minimize animations.
+pub fn prefers_reduced_motion() -> bool {
+    web_sys::window()
+        .and_then(|window| {
+            window
+                .match_media("(prefers-reduced-motion: reduce)")
+                .ok()
+                .flatten()
+        })
+        .is_some_and(|query| query.matches())
+}
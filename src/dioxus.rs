@@ -1,16 +1,24 @@
 #![doc = include_str!("../DIOXUS.md")]
 
+use crate::common::core::{ImageEvent, ImageOptions, ImageState, LoadEvent};
+use crate::common::decode::decode_image;
+use crate::common::fallback::{FallbackEvent, ImageSource, fallback_chain, resolve_fallback};
+use crate::common::observer::{Observation, observe_visibility};
+use crate::common::picture::non_empty;
+use crate::common::placeholder::inject_skeleton_keyframes;
+use crate::common::preload::preload_image;
+use crate::common::status::load_image;
+use crate::common::transition::prefers_reduced_motion;
 use crate::common::{
-    AriaLive, AriaPressed, CrossOrigin, Decoding, FetchPriority, Layout, Loading, ObjectFit,
-    Position, ReferrerPolicy,
+    AriaLive, AriaPressed, AspectRatio, Breakpoints, CrossOrigin, Decoding, Dimension,
+    FetchPriority, ImageError, ImageStatus, Layout, LazyConfig, Loader, Loading, ObjectFit,
+    Placeholder, Position, ReferrerPolicy, Source, StaticImage, Transition, UseImageOptions,
 };
 use dioxus::prelude::*;
-use gloo_net::http::Request;
-use web_sys::IntersectionObserverEntry;
-use web_sys::js_sys;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use web_sys::HtmlImageElement;
 use web_sys::wasm_bindgen::JsCast;
-use web_sys::wasm_bindgen::prelude::*;
-use web_sys::{IntersectionObserver, IntersectionObserverInit};
 
 /// Properties for the `Image` component.
 ///
@@ -29,64 +37,79 @@ pub struct ImageProps {
     ///
     /// This is the URL of the image to be displayed. This property is required for loading
     /// an image. If not provided, the image will not be displayed.
-    #[props(default = "")]
-    pub src: &'static str,
+    #[props(default = ReadOnlySignal::new(Signal::new(String::new())))]
+    pub src: ReadOnlySignal<String>,
 
     /// The alternative text for the image.
     ///
     /// This is the alt text for the image, which is used for accessibility purposes.
     /// If not provided, the alt text will be empty.
-    #[props(default = "")]
-    pub alt: &'static str,
+    #[props(into, default)]
+    pub alt: String,
 
     /// Optional fallback image.
     ///
     /// This image will be displayed if the main image fails to load. If not provided,
     /// the image will attempt to load without a fallback.
-    #[props(default = "")]
-    pub fallback_src: &'static str,
+    #[props(default = ReadOnlySignal::new(Signal::new(String::new())))]
+    pub fallback_src: ReadOnlySignal<String>,
+
+    /// Additional fallback images, tried in order after `fallback_src`.
+    ///
+    /// Each source has its own retry count, exponential backoff and per-attempt timeout.
+    /// Defaults to an empty list.
+    #[props(default)]
+    pub fallbacks: Vec<ImageSource>,
+
+    /// Callback reporting every step of the fallback chain.
+    ///
+    /// Emits a `FallbackEvent` for each retry, each source given up on and the source that
+    /// finally replaces the image. Defaults to a no-op.
+    #[props(default)]
+    pub on_fallback: Callback<FallbackEvent>,
 
     /// The width of the image.
     ///
-    /// Specifies the width of the image in pixels. It is typically used for responsive
-    /// layouts. Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub width: &'static str,
+    /// A `Dimension`, also accepted as a string such as `"800"` or `"50%"` or as a number of
+    /// pixels. It is typically used for responsive layouts. Defaults to `Dimension::Auto`.
+    #[props(into, default)]
+    pub width: Dimension,
 
     /// The height of the image.
     ///
-    /// Specifies the height of the image in pixels. Like `width`, it is often used for
-    /// responsive layouts. Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub height: &'static str,
+    /// A `Dimension`, like `width`, often used for responsive layouts. Defaults to
+    /// `Dimension::Auto`.
+    #[props(into, default)]
+    pub height: Dimension,
 
     // Common props
     /// The style attribute for the image.
     ///
     /// Allows you to apply custom inline CSS styles to the image. Defaults to an empty string.
-    #[props(default = "")]
-    pub style: &'static str,
+    #[props(into, default)]
+    pub style: String,
 
     /// The CSS class for the image.
     ///
     /// This can be used to apply custom CSS classes to the image for styling purposes.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub class: &'static str,
+    #[props(into, default)]
+    pub class: String,
 
     /// The sizes attribute for the image.
     ///
     /// This is used to define different image sizes for different viewport widths, helping
     /// with responsive images. Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub sizes: &'static str,
+    #[props(into, default)]
+    pub sizes: String,
 
     /// The quality attribute for the image.
     ///
-    /// Allows you to set the quality of the image (e.g., "low", "medium", "high"). Defaults
-    /// to an empty string if not provided.
-    #[props(default = "")]
-    pub quality: &'static str,
+    /// Allows you to set the quality of the image, either as a number from 1 to 100 or as a
+    /// hint ("low", "medium", "high"). It is forwarded to the `loader`. Defaults to an empty
+    /// string, which lets the loader pick its own quality.
+    #[props(into, default)]
+    pub quality: String,
 
     /// Indicates if the image should have priority loading.
     ///
@@ -94,20 +117,57 @@ pub struct ImageProps {
     #[props(default)]
     pub loading: Loading,
 
-    /// The placeholder attribute for the image.
+    /// Marks the image as high priority, e.g. the hero image of a page.
     ///
-    /// Allows you to specify a placeholder image URL or data URL to show while the main
-    /// image is loading. Defaults to an empty string.
-    #[props(default = "")]
-    pub placeholder: &'static str,
+    /// Priority images are loaded eagerly with `fetchpriority="high"`, skip the lazy-loading
+    /// observer and are preloaded with a `<link rel="preload">` injected into the document head.
+    /// Use it for images visible above the fold to improve LCP. Defaults to `false`.
+    #[props(default)]
+    pub priority: bool,
+
+    /// What is rendered behind the image until it is decoded.
+    ///
+    /// A blurred low-quality image, a color, a thumbnail, a BlurHash or ThumbHash, a skeleton,
+    /// or any `Element` with `Placeholder::Custom`. Defaults to `Placeholder::Empty`.
+    #[props(default)]
+    pub placeholder: Placeholder<Element>,
+
+    /// A bundled image, usually built by the `static_image!` macro.
+    ///
+    /// Provides the `src`, the `width` and `height` and a blur placeholder when they are not
+    /// set, so responsive images reserve their exact space. Defaults to `None`.
+    #[props(default)]
+    pub static_image: Option<StaticImage>,
+
+    /// The aspect ratio reserving the space of the image before it loads.
+    ///
+    /// Used by `Layout::Responsive` and `Layout::AspectRatio`. Defaults to the ratio of
+    /// `width` and `height`, then to the one of a ThumbHash placeholder, then to a square.
+    #[props(default)]
+    pub aspect_ratio: Option<AspectRatio>,
 
     /// Callback function for handling loading completion.
     ///
-    /// This callback is triggered once the image has finished loading. This is useful for
-    /// actions that should happen after the image has been fully loaded, such as hiding
-    /// a loading spinner. Defaults to a no-op.
+    /// This callback is triggered once the image, or the fallback replacing it, has been
+    /// decoded, with its URL and natural size. It fires once per source, even when the
+    /// browser picks another `srcset` candidate later on. This is useful for actions that
+    /// should happen after the image has been fully loaded, such as hiding a loading spinner.
+    /// Defaults to a no-op.
+    #[props(default)]
+    pub on_load: Callback<LoadEvent>,
+
+    /// Callback function fired with the `src` when a lazy image enters the viewport.
+    ///
+    /// Fires once per `src`, never for eager or priority images. Defaults to a no-op.
+    #[props(default)]
+    pub on_visible: Callback<String>,
+
+    /// Callback function fired with the `src` when the browser is allowed to request it.
+    ///
+    /// Fires once per `src`: on mount for eager images, once visible for lazy ones. Defaults
+    /// to a no-op.
     #[props(default)]
-    pub on_load: Callback<()>,
+    pub on_load_start: Callback<String>,
 
     // Advanced Props
     /// The object-fit attribute for the image.
@@ -140,7 +200,7 @@ pub struct ImageProps {
     /// This callback is triggered if the image fails to load, allowing you to handle
     /// error states (e.g., displaying a fallback image or showing an error message).
     #[props(default)]
-    pub on_error: Callback<String>,
+    pub on_error: Callback<ImageError>,
 
     /// The decoding attribute for the image.
     ///
@@ -148,53 +208,83 @@ pub struct ImageProps {
     /// - `Decoding::Auto`: The image decoding behavior is automatically decided by the browser.
     /// - `Decoding::Sync`: The image is decoded synchronously (blocking other tasks).
     /// - `Decoding::Async`: The image is decoded asynchronously (non-blocking).
+    /// - `Decoding::Deferred`: The image is decoded off-DOM and only revealed, without the
+    ///   blur placeholder, once decoded.
     ///
     /// Defaults to `Decoding::Auto`.
     #[props(default)]
     pub decoding: Decoding,
 
-    /// The blur data URL for placeholder image.
+    /// The transition between the placeholder and the decoded image.
     ///
-    /// This is used to display a low-quality blurred version of the image while the full
-    /// image is loading. Defaults to an empty string.
-    #[props(default = "")]
-    pub blur_data_url: &'static str,
+    /// Fades render the placeholder in its own layer behind the image and remove it once
+    /// the transition ends. Skipped when the user prefers reduced motion.
+    ///
+    /// Defaults to no transition.
+    #[props(default)]
+    pub transition: Transition,
 
     /// The lazy boundary for lazy loading.
     ///
     /// Defines the distance (in pixels) from the viewport at which the image should start
-    /// loading. Defaults to an empty string.
-    #[props(default = "")]
-    pub lazy_boundary: &'static str,
+    /// loading. Defaults to `"100px"`.
+    #[props(into, default = "100px")]
+    pub lazy_boundary: String,
+
+    /// The scroll root, margin and thresholds used to lazy load the image.
+    ///
+    /// Use it for images inside a scrolling container such as a carousel or a modal, so they
+    /// start loading before they scroll into it. Takes precedence over `lazy_boundary` when set.
+    #[props(default)]
+    pub lazy_config: Option<LazyConfig>,
 
     /// Indicates if the image should be unoptimized.
     ///
     /// If set to `true`, the image will be loaded without any optimization applied (e.g.,
-    /// no resizing or compression). Defaults to `false`.
+    /// no resizing or compression). The `loader` is skipped and `src` is used as is.
+    /// Defaults to `false`.
     #[props(default = false)]
     pub unoptimized: bool,
 
+    /// Loader used to build the final image URL.
+    ///
+    /// Receives the `src`, the `width` and the `quality` of the image and returns the URL
+    /// to fetch, e.g. a resized variant served by an image CDN. When not provided, `src`
+    /// is used as is.
+    #[props(default)]
+    pub loader: Option<Loader>,
+
     /// Image layout.
     ///
     /// Specifies how the image should be laid out within its container. Possible values
     /// include `Layout::Fill`, `Layout::Responsive`, `Layout::Intrinsic`, `Layout::Fixed`,
-    /// `Layout::Auto`, `Layout::Stretch`, and `Layout::ScaleDown`. Defaults to `Layout::Responsive`.
+    /// `Layout::Auto`, `Layout::Stretch`, `Layout::ScaleDown`, and `Layout::AspectRatio`.
+    /// Defaults to `Layout::Responsive`.
     #[props(default)]
     pub layout: Layout,
 
-    // /// Reference to the DOM node.
-    // ///
-    // /// This is used to create a reference to the actual DOM element of the image. It is
-    // /// useful for directly manipulating the image element via JavaScript if needed.
-    // // TODO: Figure out how to pass a node ref
-    // #[prop_or_default]
-    // pub node_ref: Node,
+    /// Callback function fired once the `<img>` element is mounted.
+    ///
+    /// Gives access to the underlying DOM element, e.g. to measure or scroll to the image.
+    /// Defaults to a no-op.
+    #[props(default)]
+    pub onmounted: Callback<MountedEvent>,
+
     /// A list of one or more image sources for responsive loading.
     ///
     /// Defines multiple image resources for the browser to choose from, depending on screen size, resolution,
     /// and other factors. Each source can include width (`w`) or pixel density (`x`) descriptors.
+    /// When empty and a `loader` is set, the `srcset` is generated from `breakpoints`.
+    #[props(default = ReadOnlySignal::new(Signal::new(String::new())))]
+    pub srcset: ReadOnlySignal<String>,
+
+    /// Widths used to generate the `srcset` when a `loader` is set.
+    ///
+    /// `Layout::Responsive`, `Layout::Fill` and `Layout::Stretch` images get width descriptors
+    /// built from the device widths, other layouts get 1x/2x density descriptors based on `width`.
+    /// Defaults to the Next.js device and image widths.
     #[props(default)]
-    pub srcset: &'static str,
+    pub breakpoints: Breakpoints,
 
     /// Cross-origin policy to use when fetching the image.
     ///
@@ -214,8 +304,8 @@ pub struct ImageProps {
     ///
     /// Associates the image with a `<map>` element, enabling clickable regions within the image. The value
     /// should begin with `#` and match the `name` of the corresponding map element.
-    #[props(default)]
-    pub usemap: &'static str,
+    #[props(into, default)]
+    pub usemap: String,
 
     /// Indicates that the image is part of a server-side image map.
     ///
@@ -235,42 +325,42 @@ pub struct ImageProps {
     ///
     /// Registers the image with the `PerformanceElementTiming` API using the given string as its ID. Useful for
     /// performance monitoring and analytics.
-    #[props(default)]
-    pub elementtiming: &'static str,
+    #[props(into, default)]
+    pub elementtiming: String,
 
     /// URL(s) to send Attribution Reporting requests for the image.
     ///
     /// Indicates that the browser should send an `Attribution-Reporting-Eligible` header with the image request.
     /// Can be a boolean or a list of URLs for attribution registration on specified servers. Experimental feature.
-    #[props(default)]
-    pub attributionsrc: &'static str,
+    #[props(into, default)]
+    pub attributionsrc: String,
 
     /// Indicates the current state of the image in a navigation menu.
     ///
     /// Valid values are "page", "step", "location", "date", "time", "true", "false".
     /// This is useful for enhancing accessibility in navigation menus.
-    #[props(default = "")]
-    pub aria_current: &'static str,
+    #[props(into, default)]
+    pub aria_current: String,
 
     /// Describes the image using the ID of the element that provides a description.
     ///
     /// The ID of the element that describes the image. This is used for accessibility
     /// purposes, particularly for screen readers.
-    #[props(default = "")]
-    pub aria_describedby: &'static str,
+    #[props(into, default)]
+    pub aria_describedby: String,
 
     /// Indicates whether the content associated with the image is currently expanded or collapsed.
     ///
     /// This is typically used for ARIA-based accessibility and is represented as "true" or "false".
-    #[props(default = "")]
-    pub aria_expanded: &'static str,
+    #[props(into, default)]
+    pub aria_expanded: String,
 
     /// Indicates whether the image is currently hidden from the user.
     ///
     /// This attribute is used for accessibility and indicates whether the image is visible
     /// to the user or not. Valid values are "true" or "false".
-    #[props(default = "")]
-    pub aria_hidden: &'static str,
+    #[props(into, default)]
+    pub aria_hidden: String,
 
     /// Indicates whether the content associated with the image is live and dynamic.
     ///
@@ -288,164 +378,407 @@ pub struct ImageProps {
     /// ID of the element that the image controls or owns.
     ///
     /// Specifies the ID of the element that the image controls or is associated with.
-    #[props(default = "")]
-    pub aria_controls: &'static str,
+    #[props(into, default)]
+    pub aria_controls: String,
 
     /// ID of the element that labels the image.
     ///
     /// Specifies the ID of the element that labels the image for accessibility purposes.
-    #[props(default = "")]
-    pub aria_labelledby: &'static str,
+    #[props(into, default)]
+    pub aria_labelledby: String,
 }
 
 impl Default for ImageProps {
     fn default() -> Self {
         ImageProps {
-            src: "",
-            alt: "Image",
-            width: "",
-            height: "",
-            style: "",
-            class: "",
-            sizes: "",
-            quality: "",
-            placeholder: "empty",
+            src: ReadOnlySignal::new(Signal::new(String::new())),
+            alt: String::from("Image"),
+            width: Dimension::Auto,
+            height: Dimension::Auto,
+            style: String::new(),
+            class: String::new(),
+            sizes: String::new(),
+            quality: String::new(),
+            placeholder: Placeholder::Empty,
+            static_image: None,
+            aspect_ratio: None,
             on_load: Callback::default(),
+            on_visible: Callback::default(),
+            on_load_start: Callback::default(),
+            onmounted: Callback::default(),
             object_fit: ObjectFit::default(),
             object_position: Position::default(),
             on_error: Callback::default(),
             decoding: Decoding::default(),
-            blur_data_url: "",
-            lazy_boundary: "100px",
+            transition: Transition::default(),
+            lazy_boundary: String::from("100px"),
+            lazy_config: None,
             unoptimized: false,
+            loader: None,
             layout: Layout::default(),
-            fallback_src: "",
-            srcset: "",
+            fallback_src: ReadOnlySignal::new(Signal::new(String::new())),
+            fallbacks: Vec::new(),
+            on_fallback: Callback::default(),
+            srcset: ReadOnlySignal::new(Signal::new(String::new())),
+            breakpoints: Breakpoints::default(),
             crossorigin: CrossOrigin::default(),
             loading: Loading::default(),
+            priority: false,
             referrerpolicy: ReferrerPolicy::default(),
-            usemap: "",
+            usemap: String::new(),
             ismap: false,
             fetchpriority: FetchPriority::default(),
-            elementtiming: "",
-            attributionsrc: "",
-            aria_current: "",
-            aria_describedby: "",
-            aria_expanded: "",
-            aria_hidden: "",
+            elementtiming: String::new(),
+            attributionsrc: String::new(),
+            aria_current: String::new(),
+            aria_describedby: String::new(),
+            aria_expanded: String::new(),
+            aria_hidden: String::new(),
             aria_live: AriaLive::default(),
             aria_pressed: AriaPressed::default(),
-            aria_controls: "",
-            aria_labelledby: "",
+            aria_controls: String::new(),
+            aria_labelledby: String::new(),
         }
     }
 }
 
 #[component]
 pub fn Image(props: ImageProps) -> Element {
-    // TODO: Figure out how to create a node in dioxus
-    let node_ref = Some(5);
-    let mut src = use_signal(|| props.src);
-    let on_load = props.on_load;
-    let on_error_callback = props.on_error;
-
-    // Intersection Observer effect
-    use_effect(move || {
-        // TODO: el.cast::<HtmlImageElement>()
-        let node = node_ref.as_ref();
-        if let Some(_img) = node {
-            let closure = Closure::wrap(Box::new(
-                move |entries: js_sys::Array, _: IntersectionObserver| {
-                    if let Some(entry) = entries.get(0).dyn_ref::<IntersectionObserverEntry>() {
-                        if entry.is_intersecting() {
-                            // img.set_src(props.src);
-                            on_load.call(());
-                        }
-                    }
-                },
-            )
-                as Box<dyn FnMut(js_sys::Array, IntersectionObserver)>);
+    image_element(props, &[])
+}
 
-            let options = IntersectionObserverInit::new();
-            options.set_threshold(&js_sys::Array::of1(&0.1.into()));
-            options.set_root_margin(props.lazy_boundary);
+/// Properties for the [`Picture`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct PictureProps {
+    /// The `<source>` elements, tried in order before the `<img>`.
+    ///
+    /// Serves other formats, e.g. AVIF and WebP with a JPEG `src`, or other crops per
+    /// breakpoint with a `media` query.
+    #[props(default)]
+    pub sources: Vec<Source>,
 
-            if let Ok(observer) =
-                IntersectionObserver::new_with_options(closure.as_ref().unchecked_ref(), &options)
-            {
-                // observer.observe(&img);
-                closure.forget();
-                {
-                    observer.disconnect();
-                }
+    /// The properties of the inner `<img>`, with the same behavior as an [`Image`].
+    #[props(default)]
+    pub image: ImageProps,
+}
+
+/// A `<picture>` with `<source>` elements for other formats and art direction.
+///
+/// The inner `<img>` is an [`Image`]: it is lazy loaded, shows the placeholder and goes
+/// through the fallback chain the same way. The sources are only rendered once the image is
+/// in view, and dropped once a fallback is displayed.
+///
+/// `Decoding::Deferred` falls back to `Decoding::Async`, the browser picking the source, and
+/// priority images get no `<link rel="preload">`, which could fetch another source.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use image_rs::dioxus::{ImageProps, Picture};
+/// use image_rs::{Layout, Source};
+///
+/// fn App() -> Element {
+///     let src = use_signal(|| "/images/photo.jpg".to_string());
+///     rsx! {
+///         Picture {
+///             sources: vec![
+///                 Source::avif("/images/photo.avif"),
+///                 Source::webp("/images/photo.webp"),
+///                 Source::new("/images/photo-square.jpg").with_media("(max-width: 640px)"),
+///             ],
+///             image: ImageProps {
+///                 src: src.into(),
+///                 alt: "A beautiful view".into(),
+///                 width: 800.into(),
+///                 height: 600.into(),
+///                 layout: Layout::Responsive,
+///                 ..Default::default()
+///             },
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Picture(props: PictureProps) -> Element {
+    image_element(props.image, &props.sources)
+}
+
+/// Renders an [`Image`], wrapped in a `<picture>` when it has `sources`.
+fn image_element(props: ImageProps, sources: &[Source]) -> Element {
+    let reduced_motion = use_hook(prefers_reduced_motion);
+    let (src, srcset) = ((props.src)(), (props.srcset)());
+    let options = ImageOptions {
+        src: &src,
+        width: props.width,
+        height: props.height,
+        quality: &props.quality,
+        sizes: &props.sizes,
+        srcset: &srcset,
+        sources,
+        style: &props.style,
+        placeholder: &props.placeholder,
+        static_image: props.static_image,
+        aspect_ratio: props.aspect_ratio,
+        layout: props.layout,
+        object_fit: props.object_fit,
+        object_position: props.object_position,
+        loading: props.loading.clone(),
+        fetchpriority: props.fetchpriority.clone(),
+        decoding: props.decoding,
+        transition: props.transition,
+        reduced_motion,
+        priority: props.priority,
+        loader: props.loader.as_ref(),
+        breakpoints: &props.breakpoints,
+        unoptimized: props.unoptimized,
+    };
+    let lazy = options.is_lazy();
+    let attrs = options.attributes();
+
+    // The `<img>` element, set once mounted.
+    let mut node = use_signal(|| None::<web_sys::Element>);
+    let observation = use_hook(|| Rc::new(RefCell::new(None::<Observation>)));
+    let mut state = use_signal(|| ImageState::initial(lazy));
+    // Index of the fallback of the chain currently displayed, if any.
+    let mut fallback = use_signal(|| None::<usize>);
+    // Whether the transition revealing the decoded image ended.
+    let mut transitioned = use_signal(|| false);
+    // The `src` the state belongs to, and a counter cancelling the fallback work of older ones.
+    let loaded_src = use_hook(|| Rc::new(RefCell::new(None::<String>)));
+    let generation = use_hook(|| Rc::new(Cell::new(0u32)));
+    let decode_task = use_hook(|| Rc::new(Cell::new(None::<Task>)));
+
+    // A new `src` renders from the initial state until the reset below is applied.
+    let is_current = loaded_src
+        .borrow()
+        .as_deref()
+        .is_none_or(|src| src == attrs.src);
+    let (current, fallback_index) = if is_current {
+        (state(), fallback())
+    } else {
+        (ImageState::initial(lazy), None)
+    };
+
+    let chain = Rc::new(fallback_chain(&(props.fallback_src)(), &props.fallbacks));
+    let fallback_src = fallback_index
+        .and_then(|index| chain.get(index))
+        .map(|source| source.src.clone());
+
+    // Starts over when `src` changes: resets the state and cancels any fallback still being
+    // resolved for the previous `src`.
+    {
+        let loaded_src = loaded_src.clone();
+        let generation = generation.clone();
+        use_effect(use_reactive((&attrs.src,), move |(src,)| {
+            let previous = loaded_src.borrow_mut().replace(src.clone());
+            let changed = previous.as_ref().is_some_and(|previous| *previous != src);
+            if changed {
+                generation.set(generation.get() + 1);
+                let reset = state.peek().next(ImageEvent::Reset { lazy });
+                state.set(reset);
+                fallback.set(None);
+                transitioned.set(false);
             }
-        }
-    });
+            // Lazy images start loading once visible.
+            if (previous.is_none() || changed) && !lazy {
+                props.on_load_start.call(src);
+            }
+        }));
+    }
+
+    // Lazy images wait for the `<img>` to get close to the viewport before setting `src`.
+    {
+        let observation = observation.clone();
+        let idle = current == ImageState::Idle;
+        let lazy_config = props
+            .lazy_config
+            .clone()
+            .unwrap_or_else(|| LazyConfig::from_boundary(&props.lazy_boundary));
+        use_effect(use_reactive(
+            (&attrs.src, &lazy, &idle, &lazy_config),
+            move |(src, lazy, idle, lazy_config)| {
+                let Some(img) = node() else {
+                    return;
+                };
+                // Replacing the previous observation stops observing the image for it.
+                *observation.borrow_mut() = (lazy && idle)
+                    .then(|| {
+                        observe_visibility(&img, &lazy_config, move || {
+                            state.set(state().next(ImageEvent::Visible));
+                            props.on_visible.call(src.clone());
+                            state.set(state().next(ImageEvent::LoadStart));
+                            props.on_load_start.call(src);
+                        })
+                    })
+                    .flatten();
+            },
+        ));
+    }
+    // Unmounting stops observing the image and cancels any fallback still being resolved, so
+    // no callback fires afterwards.
+    {
+        let generation = generation.clone();
+        use_drop(move || {
+            drop(observation.take());
+            generation.set(generation.get() + 1);
+        });
+    }
+
+    use_effect(use_reactive(
+        (&props.placeholder.is_skeleton(),),
+        |(skeleton,)| {
+            if skeleton {
+                inject_skeleton_keyframes();
+            }
+        },
+    ));
+
+    // Priority images are requested as early as possible through a preload hint in the head.
+    // Not those of a `<picture>`, whose preload could fetch a source the browser does not pick.
+    let preload = props.priority && attrs.sources.is_empty();
+    use_effect(use_reactive(
+        (
+            &preload,
+            &attrs.src,
+            &attrs.srcset,
+            &attrs.sizes,
+            &props.crossorigin,
+            &props.referrerpolicy,
+        ),
+        move |(priority, src, srcset, sizes, crossorigin, referrerpolicy)| {
+            if priority {
+                preload_image(&src, &srcset, &sizes, &crossorigin, referrerpolicy);
+            }
+        },
+    ));
 
     // On error handler
-    let on_error = move |_| {
-        let fallback_src = props.fallback_src;
+    let load_error = attrs.load_error(fallback_src.as_deref());
+    let error_chain = chain.clone();
+    let error_generation = generation.clone();
+    let fail = move || {
+        let mut state = state;
+        if current.is_settled() {
+            return;
+        }
+        // A displayed fallback failing moves on to the next one of the chain.
+        if let Some(source) = fallback_index {
+            props.on_fallback.call(FallbackEvent::Skipped {
+                source,
+                error: load_error.clone(),
+            });
+        }
+        let start = fallback_index.map_or(0, |index| index + 1);
+        let has_fallback = start < error_chain.len();
+        state.set(state().next(ImageEvent::Error { has_fallback }));
 
-        if fallback_src.is_empty() {
-            on_error_callback.call("Image failed to load and no fallback provided.".to_string());
+        if !has_fallback {
+            props.on_error.call(load_error.clone());
             return;
         }
 
+        let chain = error_chain.clone();
+        let generation = error_generation.clone();
+        let spawned = generation.get();
         spawn(async move {
-            match Request::get(fallback_src).send().await {
-                Ok(resp) if resp.ok() => {
-                    src.set(fallback_src);
-                    on_load.call(());
-                }
-                Ok(resp) => {
-                    let status = resp.status();
-                    let body = resp.text().await.unwrap_or_default();
-                    on_error_callback.call(format!(
-                        "Fallback image load failed: status {}, body {}",
-                        status, body
-                    ));
-                }
-                Err(e) => {
-                    on_error_callback.call(format!("Network error while loading fallback: {}", e));
+            let is_cancelled = || generation.get() != spawned;
+            match resolve_fallback(&chain, start, is_cancelled, |step| {
+                props.on_fallback.call(step)
+            })
+            .await
+            {
+                Ok((index, _)) => fallback.set(Some(index)),
+                // The `src` changed, the previous image no longer matters.
+                Err(ImageError::Aborted { .. }) => {}
+                Err(err) => {
+                    state.set(state().next(ImageEvent::Error {
+                        has_fallback: false,
+                    }));
+                    props.on_error.call(err);
                 }
             }
         });
     };
-
-    let img_style = format!(
-        "object-fit: {:?}; object-position: {:?}; {};",
-        props.object_fit, props.object_position, props.style
-    );
-
-    let blur_style = if props.placeholder == "blur" {
-        format!(
-            "background-size: {}; background-position: {:?}; filter: blur(20px); background-image: url('{}');",
-            props.sizes, props.object_position, props.blur_data_url
-        )
-    } else {
-        "".to_string()
+    let on_error = {
+        let fail = fail.clone();
+        move |_| fail()
     };
 
-    let full_style = format!("{img_style} {blur_style}");
+    // Deferred images are fetched and decoded off-DOM, then revealed with the blur removed.
+    use_effect(use_reactive(
+        (
+            &attrs.awaits_decode(current, fallback_src.as_deref()),
+            &attrs.src,
+            &attrs.srcset,
+            &attrs.sizes,
+            &props.crossorigin,
+            &props.referrerpolicy,
+        ),
+        move |(awaits_decode, src, srcset, sizes, crossorigin, referrerpolicy)| {
+            if let Some(previous) = decode_task.take() {
+                previous.cancel();
+            }
+            if !awaits_decode {
+                return;
+            }
+            let generation = generation.clone();
+            let spawned = generation.get();
+            let fail = fail.clone();
+            decode_task.set(Some(spawn(async move {
+                let result =
+                    decode_image(&src, &srcset, &sizes, &crossorigin, referrerpolicy).await;
+                if generation.get() != spawned {
+                    return;
+                }
+                match result {
+                    Ok(load) => {
+                        let decoded = state.peek().next(ImageEvent::Load);
+                        state.set(decoded);
+                        props.on_load.call(load);
+                    }
+                    Err(_) => fail(),
+                }
+            })));
+        },
+    ));
 
     let onload = move |_| {
-        props.on_load.call(());
+        // A new `srcset` candidate loading once decoded is not a new load.
+        if current.next(ImageEvent::Load) == current {
+            return;
+        }
+        state.set(state().next(ImageEvent::Load));
+        let img = node.peek();
+        if let Some(img) = img
+            .as_ref()
+            .and_then(|img| img.dyn_ref::<HtmlImageElement>())
+        {
+            props
+                .on_load
+                .call(LoadEvent::from_element(img, fallback_index));
+        }
     };
 
+    // The placeholder layer is removed once the image faded in.
+    let placeholder_style = attrs.placeholder_style(current, transitioned());
+
     let img_element = rsx! {
         img {
-            src: "{src()}",
+            src: attrs.current_src(current, fallback_src.as_deref()),
             alt: "{props.alt}",
-            width: "{props.width}",
-            height: "{props.height}",
+            width: "{attrs.width}",
+            height: "{attrs.height}",
             class: "{props.class}",
-            // TODO: Till Dioxus support this attribute
-            // sizes: "{props.sizes}",
-            decoding: props.decoding.as_str(),
-            loading: props.loading.as_str(),
-            // TODO
-            // node_ref: node_ref,
-            style: "{full_style}",
+            "sizes": "{attrs.sizes}",
+            decoding: attrs.decoding.as_str(),
+            loading: attrs.loading.as_str(),
+            onmounted: move |event: MountedEvent| {
+                if let Some(img) = event.data().downcast::<web_sys::Element>() {
+                    node.set(Some(img.clone()));
+                }
+                props.onmounted.call(event);
+            },
+            style: attrs.current_style(current),
             onerror: on_error,
             aria_current: "{props.aria_current}",
             aria_describedby: "{props.aria_describedby}",
@@ -458,82 +791,113 @@ pub fn Image(props: ImageProps) -> Element {
             role: "img",
             crossorigin: props.crossorigin.as_str(),
             referrerpolicy: props.referrerpolicy.as_str(),
-            // TODO
-            // fetchpriority: "{props.fetchpriority.as_str()}",
+            "fetchpriority": attrs.fetchpriority.as_str(),
             // TODO
             // attributionsrc: "{props.attributionsrc}",
             onload: onload,
             // TODO
             // elementtiming: "{props.elementtiming}",
-            srcset: "{props.srcset}",
+            srcset: attrs.current_srcset(current, fallback_src.as_deref()),
             ismap: "{props.ismap}",
-            usemap: "{props.usemap}"
+            usemap: "{props.usemap}",
+            ontransitionend: move |_| {
+                if current.is_settled() {
+                    transitioned.set(true);
+                }
+            },
         }
     };
 
-    match props.layout {
-        Layout::Fill => rsx! {
-            span {
-                style: "display: block; position: absolute; top: 0; left: 0; bottom: 0; right: 0;",
+    // The `<picture>` stays in place while the sources come and go, so the `<img>` is kept.
+    let img_element = if attrs.sources.is_empty() {
+        img_element
+    } else {
+        rsx! {
+            picture {
+                for source in attrs.current_sources(current, fallback_src.as_deref()) {
+                    source {
+                        "media": non_empty(&source.media),
+                        r#type: non_empty(&source.mime_type),
+                        "srcset": "{source.srcset}",
+                        "sizes": non_empty(&source.sizes),
+                    }
+                }
                 {img_element},
             }
-        },
-        Layout::Responsive => {
-            let quotient = props.height.parse::<f64>().unwrap_or(1.0)
-                / props.width.parse::<f64>().unwrap_or(1.0);
-            let padding_top = if quotient.is_finite() {
-                format!("{}%", quotient * 100.0)
-            } else {
-                "100%".to_string()
-            };
-            rsx! {
-                span {
-                    style: "display: block; position: relative;",
-                    span {
-                        style: "padding-top: {padding_top};",
-                    },
-                    {img_element},
-                },
-            }
         }
-        Layout::Intrinsic => rsx! {
-            span {
-                style: "display: inline-block; position: relative; max-width: 100%;",
-                span {
-                    style: "max-width: 100%;",
-                    {img_element},
-                },
-                img {
-                    src: "{props.blur_data_url}",
-                    style: "display: none;",
-                    alt: "{props.alt}",
-                    aria_hidden: "true",
-                },
-            }
-        },
-        Layout::Fixed => rsx! {
-            span {
-                style: "display: inline-block; position: relative;",
-                {img_element},
-            }
-        },
-        Layout::Auto => rsx! {
+    };
+
+    let img_element = rsx! {
+        if let Some(style) = placeholder_style {
             span {
-                style: "display: inline-block; position: relative;",
-                {img_element},
+                style,
+                aria_hidden: "true",
+                if let Placeholder::Custom(view) = &props.placeholder {
+                    {view.clone()}
+                }
             }
-        },
-        Layout::Stretch => rsx! {
+        }
+        {img_element},
+    };
+
+    let img_element = match attrs.wrapper_style {
+        Some(wrapper_style) => rsx! {
             span {
-                style: "display: block; width: 100%; height: 100%; position: relative;",
+                style: wrapper_style,
                 {img_element},
             }
         },
-        Layout::ScaleDown => rsx! {
-            span {
-                style: "display: inline-block; position: relative; max-width: 100%; max-height: 100%;",
-                {img_element},
+        None => img_element,
+    };
+
+    rsx! {
+        span {
+            style: attrs.container_style,
+            if let Some(spacer_style) = attrs.spacer_style {
+                span {
+                    style: spacer_style,
+                }
             }
-        },
+            {img_element},
+        }
     }
 }
+
+/// Loads `src` outside of an `<img>` and returns its load status.
+///
+/// The image goes through the same loader, state machine and fallback chain as [`Image`], so
+/// the status can drive a spinner or a button while the image is rendered elsewhere, or not at
+/// all. A change of `src` or `options` cancels the previous load and starts over.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use image_rs::dioxus::use_image;
+/// use image_rs::UseImageOptions;
+///
+/// #[component]
+/// fn Download() -> Element {
+///     let status = use_image("/images/photo.jpg", UseImageOptions::default());
+///     rsx! {
+///         button { disabled: !status().is_loaded(), "Download" }
+///     }
+/// }
+/// ```
+pub fn use_image(src: impl Into<String>, options: UseImageOptions) -> ReadOnlySignal<ImageStatus> {
+    let status = use_signal(ImageStatus::default);
+    let task = use_hook(|| Rc::new(Cell::new(None::<Task>)));
+    let src = src.into();
+    use_effect(use_reactive((&src, &options), move |(src, options)| {
+        if let Some(previous) = task.take() {
+            previous.cancel();
+        }
+        task.set(Some(spawn(async move {
+            let on_status = move |next| {
+                let mut status = status;
+                status.set(next);
+            };
+            load_image(&src, &options, || false, on_status).await;
+        })));
+    }));
+    status.into()
+}
@@ -1,17 +1,22 @@
 #![doc = include_str!("../LEPTOS.md")]
 
+use crate::common::core::{ImageEvent, ImageOptions, ImageState, LoadEvent};
+use crate::common::decode::decode_image;
+use crate::common::fallback::{FallbackEvent, ImageSource, fallback_chain, resolve_fallback};
+use crate::common::observer::{Observation, observe_visibility};
+use crate::common::picture::non_empty;
+use crate::common::placeholder::inject_skeleton_keyframes;
+use crate::common::preload::preload_image;
+use crate::common::status::load_image;
+use crate::common::transition::prefers_reduced_motion;
 use crate::common::{
-    CrossOrigin, Decoding, FetchPriority, Layout, Loading, ObjectFit, Position, ReferrerPolicy,
+    AspectRatio, Breakpoints, CrossOrigin, Decoding, Dimension, FetchPriority, ImageError,
+    ImageStatus, Layout, LazyConfig, Loader, Loading, ObjectFit, Placeholder, Position,
+    ReferrerPolicy, Source, StaticImage, Transition, UseImageOptions,
 };
-use gloo_net::http::Request;
 use leptos::callback::Callback;
 use leptos::task::spawn_local;
-use leptos::{html::*, prelude::*, *};
-use web_sys::IntersectionObserverEntry;
-use web_sys::js_sys;
-use web_sys::wasm_bindgen::JsCast;
-use web_sys::wasm_bindgen::closure::Closure;
-use web_sys::{IntersectionObserver, IntersectionObserverInit, RequestCache};
+use leptos::{html::*, prelude::*};
 
 // Comment out aria attrs cause of: tachys-0.2.0/src/html/attribute/mod.rs:593:1:
 // not yet implemented: adding more than 26 attributes is not supported
@@ -20,51 +25,89 @@ pub fn Image(
     /// The source URL of the image.
     ///
     /// This is the primary image that will be rendered.
-    #[prop(optional)]
-    src: &'static str,
+    #[prop(into, optional)]
+    src: Signal<String>,
 
     /// The alternative text for the image.
     ///
     /// Used for accessibility and shown if the image cannot be displayed.
-    #[prop(optional, default = "Image")]
-    alt: &'static str,
+    #[prop(into, default = "Image".into())]
+    alt: MaybeProp<String>,
 
     /// A fallback image URL if the main image fails to load.
-    #[prop(optional)]
-    fallback_src: &'static str,
+    #[prop(into, optional)]
+    fallback_src: Signal<String>,
 
-    /// Width of the image (e.g., "100px", "auto").
+    /// Additional fallback images, tried in order after `fallback_src`, each with its own
+    /// retries, backoff and timeout.
     #[prop(optional)]
-    width: &'static str,
+    fallbacks: Vec<ImageSource>,
 
-    /// Height of the image (e.g., "100px", "auto").
+    /// Callback function fired on every step of the fallback chain.
     #[prop(optional)]
-    height: &'static str,
+    on_fallback: Option<Callback<FallbackEvent>>,
+
+    /// Width of the image, e.g. `Dimension::Px(800.0)` or `Dimension::Percent(50.0)`.
+    #[prop(into, optional)]
+    width: Signal<Dimension>,
+
+    /// Height of the image, e.g. `Dimension::Px(600.0)`.
+    #[prop(into, optional)]
+    height: Signal<Dimension>,
 
     /// Inline styles applied to the image.
-    #[prop(optional)]
-    style: &'static str,
+    #[prop(into, optional)]
+    style: Signal<String>,
 
     /// CSS class name(s) to apply to the image.
-    #[prop(optional)]
-    class: &'static str,
+    #[prop(into, optional)]
+    class: MaybeProp<String>,
 
     /// Image `sizes` attribute for responsive loading.
-    #[prop(optional)]
-    sizes: &'static str,
+    #[prop(into, optional)]
+    sizes: Signal<String>,
+
+    /// Image quality forwarded to the `loader`, from 1 to 100 or a hint ("low", "medium", "high").
+    #[prop(into, optional)]
+    quality: String,
 
-    // #[prop(optional)] quality: &'static str,
     /// Defines how the image is loaded. Defaults to lazy loading.
     #[prop(optional, default = Loading::Lazy)]
     loading: Loading,
 
-    /// Placeholder content shown while the image loads.
-    #[prop(optional, default = "empty")]
-    placeholder: &'static str,
+    /// Loads the image eagerly with a high fetch priority, skips the lazy-loading observer and
+    /// preloads it with a `<link rel="preload">` in the document head. Use it for LCP images.
+    #[prop(optional, default = false)]
+    priority: bool,
 
-    /// Callback function fired when the image is successfully loaded.
+    /// What is rendered behind the image until it is decoded, e.g. a blurred low-quality image,
+    /// a color, a skeleton or any view with `Placeholder::Custom`.
     #[prop(optional)]
-    on_load: Option<Callback<()>>,
+    placeholder: Placeholder<ViewFn>,
+
+    /// A bundled image, usually built by the `static_image!` macro, providing the `src`, the
+    /// `width` and `height` and a blur placeholder when they are not set.
+    #[prop(optional, into)]
+    static_image: Option<StaticImage>,
+
+    /// The aspect ratio reserving the space of a `Layout::Responsive` or `Layout::AspectRatio`
+    /// image, derived from `width` and `height`, then from a ThumbHash placeholder when unset.
+    #[prop(optional, into)]
+    aspect_ratio: Option<AspectRatio>,
+
+    /// Callback function fired once the image, or the fallback replacing it, is decoded, with
+    /// its URL and natural size. Fires once per source.
+    #[prop(optional)]
+    on_load: Option<Callback<LoadEvent>>,
+
+    /// Callback function fired with the `src` when a lazy image enters the viewport.
+    #[prop(optional)]
+    on_visible: Option<Callback<String>>,
+
+    /// Callback function fired with the `src` when the browser is allowed to request it: on
+    /// mount for eager images, once visible for lazy ones.
+    #[prop(optional)]
+    on_load_start: Option<Callback<String>>,
 
     /// Specifies how the image should be resized to fit its container.
     #[prop(optional, default = ObjectFit::Fill)]
@@ -76,18 +119,35 @@ pub fn Image(
 
     /// Callback function fired when the image fails to load.
     #[prop(optional)]
-    on_error: Option<Callback<String>>,
+    on_error: Option<Callback<ImageError>>,
 
-    /// Specifies how the image should be decoded (auto, sync, async).
+    /// Specifies how the image should be decoded (auto, sync, async, or deferred to reveal it
+    /// once decoded off-DOM).
     #[prop(optional, default = Decoding::Auto)]
     decoding: Decoding,
 
-    /// Base64-encoded blurred image shown before the main image loads.
+    /// Transition between the placeholder and the decoded image, skipped when the user prefers
+    /// reduced motion.
     #[prop(optional)]
-    blur_data_url: &'static str,
+    transition: Transition,
+
+    /// Distance from the viewport at which a lazy image starts loading (e.g. "100px").
+    #[prop(into, default = "100px".into())]
+    lazy_boundary: String,
+
+    /// Scroll root, margin and thresholds used to lazy load the image, e.g. inside a carousel
+    /// or a modal. Takes precedence over `lazy_boundary` when set.
+    #[prop(optional)]
+    lazy_config: Option<LazyConfig>,
+
+    /// Skips the `loader` and uses `src` as is.
+    #[prop(optional, default = false)]
+    unoptimized: bool,
+
+    /// Builds the final image URL from `src`, `width` and `quality` (e.g. an image CDN).
+    #[prop(optional)]
+    loader: Option<Loader>,
 
-    // #[prop(optional, default = "100px")] lazy_boundary: &'static str,
-    // #[prop(optional, default = false)] unoptimized: bool,
     /// Controls how the image is laid out inside its container.
     #[prop(optional, default = Layout::Responsive)]
     layout: Layout,
@@ -97,8 +157,14 @@ pub fn Image(
     node_ref: NodeRef<Img>,
 
     /// One or more image sources with descriptors (e.g., "img-1x.jpg 1x, img-2x.jpg 2x").
+    ///
+    /// Generated from `breakpoints` when empty and a `loader` is set.
+    #[prop(into, optional)]
+    srcset: Signal<String>,
+
+    /// Device and image widths used to generate the `srcset` when a `loader` is set.
     #[prop(optional)]
-    srcset: &'static str,
+    breakpoints: Breakpoints,
 
     /// CORS policy for fetching the image (none, anonymous, use-credentials).
     #[prop(optional, default = CrossOrigin::None)]
@@ -109,8 +175,8 @@ pub fn Image(
     referrerpolicy: ReferrerPolicy,
 
     /// Associates the image with an image map.
-    #[prop(optional)]
-    usemap: &'static str,
+    #[prop(into, optional)]
+    usemap: MaybeProp<String>,
 
     /// Indicates the image is part of a server-side image map.
     #[prop(optional, default = false)]
@@ -121,8 +187,8 @@ pub fn Image(
     fetchpriority: FetchPriority,
 
     /// Identifier for performance element timing.
-    #[prop(optional)]
-    elementtiming: &'static str,
+    #[prop(into, optional)]
+    elementtiming: MaybeProp<String>,
     /// Indicates the current item in a set for accessibility.
     // #[prop(optional)] aria_current: &'static str,
     /// ID reference to the element describing this image.
@@ -140,408 +206,550 @@ pub fn Image(
     /// Indicates whether updates to the image are live.
     // #[prop(optional, default = AriaLive::Off)] aria_live: AriaLive,
     /// URLs for Attribution Reporting (experimental feature).
-    #[prop(optional)]
-    attributionsrc: &'static str,
+    #[prop(into, optional)]
+    attributionsrc: MaybeProp<String>,
 ) -> impl IntoView {
-    let (img_src, set_img_src) = signal(src);
+    image_view(
+        ImageProps {
+            src,
+            alt,
+            fallback_src,
+            fallbacks,
+            on_fallback,
+            width,
+            height,
+            style,
+            class,
+            sizes,
+            quality,
+            loading,
+            priority,
+            placeholder,
+            static_image,
+            aspect_ratio,
+            on_load,
+            on_visible,
+            on_load_start,
+            object_fit,
+            object_position,
+            on_error,
+            decoding,
+            transition,
+            lazy_boundary,
+            lazy_config,
+            unoptimized,
+            loader,
+            layout,
+            node_ref,
+            srcset,
+            breakpoints,
+            crossorigin,
+            referrerpolicy,
+            usemap,
+            ismap,
+            fetchpriority,
+            elementtiming,
+            attributionsrc,
+        },
+        Vec::new(),
+    )
+}
 
-    Effect::new(move || {
-        let callback = Closure::wrap(Box::new(
-            move |entries: js_sys::Array, _observer: IntersectionObserver| {
-                if let Some(entry) = entries.get(0).dyn_ref::<IntersectionObserverEntry>() {
-                    if entry.is_intersecting() {
-                        if let Some(node) = node_ref.get() {
-                            if let Some(img) = node.dyn_ref::<web_sys::HtmlImageElement>() {
-                                img.set_src(src);
-                                if let Some(cb) = on_load {
-                                    cb.run(());
-                                }
-                            }
-                        }
-                    }
-                }
-            },
-        )
-            as Box<dyn FnMut(js_sys::Array, IntersectionObserver)>);
+/// A `<picture>` with `<source>` elements for other formats and art direction.
+///
+/// The inner `<img>` is an [`Image`] built from `image`: it is lazy loaded, shows the
+/// placeholder and goes through the fallback chain the same way. The sources are only rendered
+/// once the image is in view, and dropped once a fallback is displayed.
+///
+/// `Decoding::Deferred` falls back to `Decoding::Async`, the browser picking the source, and
+/// priority images get no `<link rel="preload">`, which could fetch another source.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use image_rs::leptos::{ImageProps, Picture};
+/// use image_rs::{Dimension, Layout, Source};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let sources = vec![
+///         Source::avif("/images/photo.avif"),
+///         Source::webp("/images/photo.webp"),
+///         Source::new("/images/photo-square.jpg").with_media("(max-width: 640px)"),
+///     ];
+///     let image = ImageProps::builder()
+///         .src("/images/photo.jpg")
+///         .alt("A beautiful view")
+///         .width(Dimension::Px(800.0))
+///         .height(Dimension::Px(600.0))
+///         .layout(Layout::Responsive)
+///         .build();
+///     view! { <Picture sources=sources image=image /> }
+/// }
+/// ```
+#[component]
+pub fn Picture(
+    /// The `<source>` elements, tried in order before the `<img>`, e.g. AVIF and WebP with a
+    /// JPEG `src`, or other crops per breakpoint with a `media` query.
+    #[prop(into, optional)]
+    sources: Vec<Source>,
+
+    /// The props of the inner `<img>`, with the same behavior as an [`Image`].
+    image: ImageProps,
+) -> impl IntoView {
+    image_view(image, sources)
+}
 
-        let options = IntersectionObserverInit::new();
-        options.set_threshold(&js_sys::Array::of1(&0.1.into()));
+/// Renders an [`Image`], wrapped in a `<picture>` when it has `sources`.
+fn image_view(props: ImageProps, sources: Vec<Source>) -> impl IntoView {
+    let ImageProps {
+        src,
+        alt,
+        fallback_src,
+        fallbacks,
+        on_fallback,
+        width,
+        height,
+        style,
+        class,
+        sizes,
+        quality,
+        loading,
+        priority,
+        placeholder,
+        static_image,
+        aspect_ratio,
+        on_load,
+        on_visible,
+        on_load_start,
+        object_fit,
+        object_position,
+        on_error,
+        decoding,
+        transition,
+        lazy_boundary,
+        lazy_config,
+        unoptimized,
+        loader,
+        layout,
+        node_ref,
+        srcset,
+        breakpoints,
+        crossorigin,
+        referrerpolicy,
+        usemap,
+        ismap,
+        fetchpriority,
+        elementtiming,
+        attributionsrc,
+    } = props;
+    let lazy = !priority && loading != Loading::Eager;
+    let custom_placeholder = match &placeholder {
+        Placeholder::Custom(view) => Some(view.clone()),
+        _ => None,
+    };
+    if placeholder.is_skeleton() {
+        Effect::new(inject_skeleton_keyframes);
+    }
+    let reduced_motion = prefers_reduced_motion();
+    let picture = !sources.is_empty();
+    let attrs = Memo::new(move |_| {
+        ImageOptions {
+            src: &src.read(),
+            width: width.get(),
+            height: height.get(),
+            quality: &quality,
+            sizes: &sizes.read(),
+            srcset: &srcset.read(),
+            sources: &sources,
+            style: &style.read(),
+            placeholder: &placeholder,
+            static_image,
+            aspect_ratio,
+            layout,
+            object_fit,
+            object_position,
+            loading: loading.clone(),
+            fetchpriority: fetchpriority.clone(),
+            decoding,
+            transition,
+            reduced_motion,
+            priority,
+            loader: loader.as_ref(),
+            breakpoints: &breakpoints,
+            unoptimized,
+        }
+        .attributes()
+    });
 
-        let observer =
-            IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &options)
-                .expect("Failed to create IntersectionObserver");
+    let state = RwSignal::new(ImageState::initial(lazy));
+    // Index of the fallback of the chain currently displayed, if any.
+    let fallback = RwSignal::new(None::<usize>);
+    // Whether the transition revealing the decoded image ended.
+    let transitioned = RwSignal::new(false);
+    let chain = Memo::new(move |_| fallback_chain(&fallback_src.read(), &fallbacks));
+
+    // The `src` the state belongs to, and a counter bumped on every change of `src` so that a
+    // fallback chain still running for the previous image gives up.
+    let loaded_src = StoredValue::new(None::<String>);
+    let generation = StoredValue::new(0u32);
+
+    // Until the reset below runs, the state still describes the previous `src`.
+    let current = move || {
+        let (state, fallback) = (state.get(), fallback.get());
+        let is_current = attrs.with(|attrs| {
+            loaded_src.with_value(|loaded| loaded.as_deref().is_none_or(|src| src == attrs.src))
+        });
+        if is_current {
+            (state, fallback)
+        } else {
+            (ImageState::initial(lazy), None)
+        }
+    };
 
-        if let Some(element) = node_ref.get() {
-            if let Ok(img) = element.clone().dyn_into::<web_sys::HtmlElement>() {
-                observer.observe(&img);
+    Effect::new(move || {
+        let src = attrs.with(|attrs| attrs.src.clone());
+        let previous = loaded_src
+            .try_update_value(|loaded| loaded.replace(src.clone()))
+            .flatten();
+        let changed = previous.as_ref().is_some_and(|previous| *previous != src);
+        if changed {
+            generation.update_value(|generation| *generation += 1);
+            state.update(|state| *state = state.next(ImageEvent::Reset { lazy }));
+            fallback.set(None);
+            transitioned.set(false);
+        }
+        // Lazy images start loading once visible.
+        if (previous.is_none() || changed) && !lazy {
+            if let Some(cb) = on_load_start {
+                cb.run(src);
             }
         }
+    });
+
+    // Priority images are requested as early as possible through a preload hint in the head.
+    // Not those of a `<picture>`, whose preload could fetch a source the browser does not pick.
+    let preload_crossorigin = crossorigin.clone();
+    Effect::new(move || {
+        if priority && !picture {
+            attrs.with(|attrs| {
+                preload_image(
+                    &attrs.src,
+                    &attrs.srcset,
+                    &attrs.sizes,
+                    &preload_crossorigin,
+                    referrerpolicy,
+                )
+            });
+        }
+    });
 
-        let observer_clone = observer.clone();
-        let _cleanup = move || {
-            observer_clone.disconnect();
+    let lazy_config = lazy_config.unwrap_or_else(|| LazyConfig::from_boundary(&lazy_boundary));
+    let idle = Memo::new(move |_| current().0 == ImageState::Idle);
+    let observation = StoredValue::new_local(None::<Observation>);
+    Effect::new(move || {
+        let next = if lazy && idle.get() {
+            node_ref.get().and_then(|img| {
+                observe_visibility(&img, &lazy_config, move || {
+                    let src = attrs.with_untracked(|attrs| attrs.src.clone());
+                    state.update(|state| *state = state.next(ImageEvent::Visible));
+                    if let Some(cb) = on_visible {
+                        cb.run(src.clone());
+                    }
+                    state.update(|state| *state = state.next(ImageEvent::LoadStart));
+                    if let Some(cb) = on_load_start {
+                        cb.run(src);
+                    }
+                })
+            })
+        } else {
+            None
         };
+        // Replacing the previous observation stops observing the image for it.
+        observation.set_value(next);
+    });
 
-        callback.forget();
+    // Unmounting stops observing the image and cancels any fallback still being resolved, so
+    // no callback fires afterwards.
+    on_cleanup(move || {
+        observation.try_set_value(None);
+        generation.try_update_value(|generation| *generation += 1);
     });
 
     let onload = move |_| {
-        if let Some(cb) = on_load {
-            cb.run(());
+        let (current, fallback_index) = untrack(current);
+        // A new `srcset` candidate loading once decoded is not a new load.
+        if current.next(ImageEvent::Load) == current {
+            return;
+        }
+        state.update(|state| *state = state.next(ImageEvent::Load));
+        if let (Some(img), Some(cb)) = (node_ref.get_untracked(), on_load) {
+            cb.run(LoadEvent::from_element(&img, fallback_index));
         }
     };
 
-    let onerror = {
-        move |_| {
-            spawn_local(async move {
-                match Request::get(fallback_src)
-                    .cache(RequestCache::Reload)
-                    .send()
-                    .await
-                {
-                    Ok(res) if res.status() == 200 => match res.json::<serde_json::Value>().await {
-                        Ok(_) => {
-                            set_img_src.set(fallback_src);
-                            if let Some(cb) = on_load {
-                                cb.run(());
-                            }
-                        }
-                        Err(_) => {
-                            if let Some(cb) = on_error {
-                                cb.run("Image not found!".to_string());
-                            }
-                        }
-                    },
-                    Ok(res) => {
-                        let body = res.text().await.unwrap_or_default();
-                        if let Some(cb) = on_error {
-                            cb.run(format!(
-                                "Failed to load image. Status: {}, Body: {}",
-                                res.status(),
-                                body
-                            ));
-                        }
+    let fail = move || {
+        let (current, fallback_index) = untrack(current);
+        if current.is_settled() {
+            return;
+        }
+        let chain = chain.get_untracked();
+        let load_error = attrs.with_untracked(|attrs| {
+            attrs.load_error(
+                fallback_index
+                    .and_then(|index| chain.get(index))
+                    .map(|source| source.src.as_str()),
+            )
+        });
+        // A displayed fallback failing moves on to the next one of the chain.
+        if let (Some(source), Some(cb)) = (fallback_index, on_fallback) {
+            cb.run(FallbackEvent::Skipped {
+                source,
+                error: load_error.clone(),
+            });
+        }
+        let start = fallback_index.map_or(0, |index| index + 1);
+        let has_fallback = start < chain.len();
+        state.update(|state| *state = state.next(ImageEvent::Error { has_fallback }));
+
+        if !has_fallback {
+            if let Some(cb) = on_error {
+                cb.run(load_error);
+            }
+            return;
+        }
+
+        let started = generation.get_value();
+        spawn_local(async move {
+            let is_cancelled = || generation.try_get_value() != Some(started);
+            let on_step = |step| {
+                if let Some(cb) = on_fallback {
+                    cb.run(step);
+                }
+            };
+            match resolve_fallback(&chain, start, is_cancelled, on_step).await {
+                Ok((index, _)) => fallback.set(Some(index)),
+                Err(ImageError::Aborted { .. }) => {}
+                Err(err) => {
+                    state.update(|state| {
+                        *state = state.next(ImageEvent::Error {
+                            has_fallback: false,
+                        })
+                    });
+                    if let Some(cb) = on_error {
+                        cb.run(err);
                     }
-                    Err(e) => {
-                        if let Some(cb) = on_error {
-                            cb.run(format!("Network error: {e}"));
-                        }
+                }
+            }
+        });
+    };
+    let onerror = move |_| fail();
+
+    let fallback_src = move |index: Option<usize>| {
+        chain.with(|chain| {
+            index
+                .and_then(|index| chain.get(index))
+                .map(|source| source.src.clone())
+        })
+    };
+
+    // Deferred images are fetched and decoded off-DOM, then revealed with the blur removed.
+    let decode_crossorigin = crossorigin.clone();
+    Effect::new(move || {
+        let (image_state, fallback_index) = current();
+        let fallback_src = fallback_src(fallback_index);
+        let Some((src, srcset, sizes)) = attrs.with(|attrs| {
+            attrs
+                .awaits_decode(image_state, fallback_src.as_deref())
+                .then(|| (attrs.src.clone(), attrs.srcset.clone(), attrs.sizes.clone()))
+        }) else {
+            return;
+        };
+        let crossorigin = decode_crossorigin.clone();
+        let started = generation.get_value();
+        spawn_local(async move {
+            let result = decode_image(&src, &srcset, &sizes, &crossorigin, referrerpolicy).await;
+            // Only the first decode of the image counts, later ones come from a new `srcset`.
+            let (current, _) = untrack(current);
+            if generation.try_get_value() != Some(started) || current != ImageState::Loading {
+                return;
+            }
+            match result {
+                Ok(load) => {
+                    state.update(|state| *state = state.next(ImageEvent::Load));
+                    if let Some(cb) = on_load {
+                        cb.run(load);
                     }
                 }
-            });
-        }
+                Err(_) => fail(),
+            }
+        });
+    });
+    let current_src = move || {
+        let (current, fallback_index) = current();
+        let fallback_src = fallback_src(fallback_index);
+        attrs.with(|attrs| attrs.current_src(current, fallback_src.as_deref()))
+    };
+    let current_srcset = move || {
+        let (current, fallback_index) = current();
+        let fallback_src = fallback_src(fallback_index);
+        attrs.with(|attrs| attrs.current_srcset(current, fallback_src.as_deref()))
     };
 
-    let img_style = format!(
-        "{} object-fit: {}; object-position: {};",
-        style,
-        object_fit.as_str(),
-        object_position.as_str()
-    );
+    // The placeholder layer is removed once the image faded in.
+    let placeholder_layer = move || {
+        let (current, _) = current();
+        attrs
+            .with(|attrs| attrs.placeholder_style(current, transitioned.get()))
+            .map(|style| {
+                let custom = custom_placeholder.clone().map(|view| view.run());
+                view! { <span style=style aria-hidden="true">{custom}</span> }
+            })
+    };
+    let ontransitionend = move |_| {
+        if untrack(current).0.is_settled() {
+            transitioned.set(true);
+        }
+    };
 
-    let blur_style = if placeholder == "blur" && !blur_data_url.is_empty() {
-        format!(
-            "background-size: {}; background-position: {}; filter: blur(20px); background-image: url('{}');",
-            sizes,
-            object_position.as_str(),
-            blur_data_url
-        )
-    } else {
-        "".into()
+    let current_sources = move || {
+        let (current, fallback_index) = current();
+        let fallback_src = fallback_src(fallback_index);
+        attrs.with(|attrs| {
+            attrs
+                .current_sources(current, fallback_src.as_deref())
+                .to_vec()
+        })
     };
 
-    let full_style = format!("{blur_style} {img_style}");
-
-    let layout_view = match layout {
-        Layout::Fill => view! {
-            <span style="display:block; position:absolute; top:0; left:0; right:0; bottom:0;">
-                <img
-                    node_ref=node_ref
-                    src=move || img_src.get()
-                    alt=alt
-                    class=class
-                    width=width
-                    height=height
-                    style=full_style.clone()
-                    sizes=sizes
-                    srcset=srcset
-                    decoding=decoding.as_str()
-                    crossorigin=crossorigin.as_str()
-                    referrerpolicy=referrerpolicy.as_str()
-                    loading=loading.as_str()
-                    fetchpriority=fetchpriority.as_str()
-                    aria_placeholder=placeholder
-                    on:load=onload
-                    on:error=onerror
-                    role="img"
-                    // aria-label=alt
-                    // aria-labelledby=aria_labelledby
-                    // aria-describedby=aria_describedby
-                    // aria-hidden=aria_hidden
-                    // aria-current=aria_current
-                    // aria-expanded=aria_expanded
-                    // aria-live=aria_live.as_str()
-                    // aria-pressed=aria_pressed.as_str()
-                    // aria-controls=aria_controls
-                    usemap=usemap
-                    ismap=ismap
-                    elementtiming=elementtiming
-                    attributionsrc=attributionsrc
-                />
-            </span>
-        }
-        .into_any(),
-
-        Layout::Responsive => {
-            let ratio = height.parse::<f64>().unwrap_or(1.0) / width.parse::<f64>().unwrap_or(1.0);
-            let padding = format!("{}%", ratio * 100.0);
-            view! {
-                <span style="display:block; position:relative;">
-                    <span style=format!("padding-top: {padding}")>
-                        <img
-                            node_ref=node_ref
-                            src=move || img_src.get()
-                            alt=alt
-                            class=class
-                            width=width
-                            height=height
-                            style=full_style.clone()
-                            sizes=sizes
-                            srcset=srcset
-                            decoding=decoding.as_str()
-                            crossorigin=crossorigin.as_str()
-                            referrerpolicy=referrerpolicy.as_str()
-                            loading=loading.as_str()
-                            fetchpriority=fetchpriority.as_str()
-                            aria_placeholder=placeholder
-                            on:load=onload
-                            on:error=onerror
-                            role="img"
-                            // aria-label=alt
-                            // aria-labelledby=aria_labelledby
-                            // aria-describedby=aria_describedby
-                            // aria-hidden=aria_hidden
-                            // aria-current=aria_current
-                            // aria-expanded=aria_expanded
-                            // aria-live=aria_live.as_str()
-                            // aria-pressed=aria_pressed.as_str()
-                            // aria-controls=aria_controls
-                            usemap=usemap
-                            ismap=ismap
-                            elementtiming=elementtiming
-                            attributionsrc=attributionsrc
-                        />
-                    </span>
-                </span>
+    let img = view! {
+        <img
+            node_ref=node_ref
+            src=current_src
+            alt=move || alt.get()
+            class=move || class.get()
+            width=move || attrs.with(|attrs| attrs.width.clone())
+            height=move || attrs.with(|attrs| attrs.height.clone())
+            style=move || {
+                let (current, _) = current();
+                attrs.with(|attrs| attrs.current_style(current))
             }
-            .into_any()
+            sizes=move || attrs.with(|attrs| attrs.sizes.clone())
+            srcset=current_srcset
+            decoding=move || attrs.with(|attrs| attrs.decoding.as_str())
+            crossorigin=crossorigin.as_str()
+            referrerpolicy=referrerpolicy.as_str()
+            loading=move || attrs.with(|attrs| attrs.loading.as_str())
+            fetchpriority=move || attrs.with(|attrs| attrs.fetchpriority.as_str())
+            on:load=onload
+            on:error=onerror
+            on:transitionend=ontransitionend
+            role="img"
+            // aria-label=alt
+            // aria-labelledby=aria_labelledby
+            // aria-describedby=aria_describedby
+            // aria-hidden=aria_hidden
+            // aria-current=aria_current
+            // aria-expanded=aria_expanded
+            // aria-live=aria_live.as_str()
+            // aria-pressed=aria_pressed.as_str()
+            // aria-controls=aria_controls
+            usemap=move || usemap.get()
+            ismap=ismap
+            elementtiming=move || elementtiming.get()
+            attributionsrc=move || attributionsrc.get()
+        />
+    };
+    // The `<picture>` stays in place while the sources come and go, so the `<img>` is kept.
+    let img = if picture {
+        view! {
+            <picture>
+                {move || {
+                    current_sources()
+                        .into_iter()
+                        .map(|source| {
+                            view! {
+                                <source
+                                    media=non_empty(&source.media)
+                                    type=non_empty(&source.mime_type)
+                                    srcset=source.srcset
+                                    sizes=non_empty(&source.sizes)
+                                />
+                            }
+                        })
+                        .collect_view()
+                }}
+                {img}
+            </picture>
         }
+        .into_any()
+    } else {
+        img.into_any()
+    };
+    let img = view! {
+        {placeholder_layer}
+        {img}
+    };
 
-        Layout::Intrinsic => view! {
-            <span style="display:inline-block; position:relative; max-width:100%;">
-                <span style="max-width:100%;">
-                    <img
-                        node_ref=node_ref
-                        src=move || img_src.get()
-                        alt=alt
-                        class=class
-                        width=width
-                        height=height
-                        style=full_style.clone()
-                        sizes=sizes
-                        srcset=srcset
-                        decoding=decoding.as_str()
-                        crossorigin=crossorigin.as_str()
-                        referrerpolicy=referrerpolicy.as_str()
-                        loading=loading.as_str()
-                        fetchpriority=fetchpriority.as_str()
-                        aria_placeholder=placeholder
-                        on:load=onload
-                        on:error=onerror
-                        role="img"
-                        // aria-label=alt
-                        // aria-labelledby=aria_labelledby
-                        // aria-describedby=aria_describedby
-                        // aria-hidden=aria_hidden
-                        // aria-current=aria_current
-                        // aria-expanded=aria_expanded
-                        // aria-live=aria_live.as_str()
-                        // aria-pressed=aria_pressed.as_str()
-                        // aria-controls=aria_controls
-                        usemap=usemap
-                        ismap=ismap
-                        elementtiming=elementtiming
-                        attributionsrc=attributionsrc
-                    />
-                </span>
-                <img
-                    src=blur_data_url
-                    style="display:none;"
-                    alt=alt
-                    aria-hidden="true"
-                />
-            </span>
-        }
-        .into_any(),
-
-        Layout::Fixed => view! {
-            <span style="display:inline-block; position:relative;">
-                <img
-                    node_ref=node_ref
-                    src=move || img_src.get()
-                    alt=alt
-                    class=class
-                    width=width
-                    height=height
-                    style=full_style.clone()
-                    sizes=sizes
-                    srcset=srcset
-                    decoding=decoding.as_str()
-                    crossorigin=crossorigin.as_str()
-                    referrerpolicy=referrerpolicy.as_str()
-                    loading=loading.as_str()
-                    fetchpriority=fetchpriority.as_str()
-                    aria_placeholder=placeholder
-                    on:load=onload
-                    on:error=onerror
-                    role="img"
-                    // aria-label=alt
-                    // aria-labelledby=aria_labelledby
-                    // aria-describedby=aria_describedby
-                    // aria-hidden=aria_hidden
-                    // aria-current=aria_current
-                    // aria-expanded=aria_expanded
-                    // aria-live=aria_live.as_str()
-                    // aria-pressed=aria_pressed.as_str()
-                    // aria-controls=aria_controls
-                    usemap=usemap
-                    ismap=ismap
-                    elementtiming=elementtiming
-                    attributionsrc=attributionsrc
-                />
-            </span>
-        }
-        .into_any(),
-
-        Layout::Auto => view! {
-            <span style="display:inline-block; position:relative;">
-                <img
-                    node_ref=node_ref
-                    src=move || img_src.get()
-                    alt=alt
-                    class=class
-                    width=width
-                    height=height
-                    style=full_style.clone()
-                    sizes=sizes
-                    srcset=srcset
-                    decoding=decoding.as_str()
-                    crossorigin=crossorigin.as_str()
-                    referrerpolicy=referrerpolicy.as_str()
-                    loading=loading.as_str()
-                    fetchpriority=fetchpriority.as_str()
-                    aria_placeholder=placeholder
-                    on:load=onload
-                    on:error=onerror
-                    role="img"
-                    // aria-label=alt
-                    // aria-labelledby=aria_labelledby
-                    // aria-describedby=aria_describedby
-                    // aria-hidden=aria_hidden
-                    // aria-current=aria_current
-                    // aria-expanded=aria_expanded
-                    // aria-live=aria_live.as_str()
-                    // aria-pressed=aria_pressed.as_str()
-                    // aria-controls=aria_controls
-                    usemap=usemap
-                    ismap=ismap
-                    elementtiming=elementtiming
-                    attributionsrc=attributionsrc
-                />
-            </span>
-        }
-        .into_any(),
-
-        Layout::Stretch => view! {
-            <span style="display:block; width:100%; height:100%; position:relative;">
-                <img
-                    node_ref=node_ref
-                    src=move || img_src.get()
-                    alt=alt
-                    class=class
-                    width="100%"
-                    height="100%"
-                    style=full_style.clone()
-                    sizes=sizes
-                    srcset=srcset
-                    decoding=decoding.as_str()
-                    crossorigin=crossorigin.as_str()
-                    referrerpolicy=referrerpolicy.as_str()
-                    loading=loading.as_str()
-                    fetchpriority=fetchpriority.as_str()
-                    aria_placeholder=placeholder
-                    on:load=onload
-                    on:error=onerror
-                    role="img"
-                    // aria-label=alt
-                    // aria-labelledby=aria_labelledby
-                    // aria-describedby=aria_describedby
-                    // aria-hidden=aria_hidden
-                    // aria-current=aria_current
-                    // aria-expanded=aria_expanded
-                    // aria-live=aria_live.as_str()
-                    // aria-pressed=aria_pressed.as_str()
-                    // aria-controls=aria_controls
-                    usemap=usemap
-                    ismap=ismap
-                    elementtiming=elementtiming
-                    attributionsrc=attributionsrc
-                />
-            </span>
-        }
-        .into_any(),
-
-        Layout::ScaleDown => view! {
-            <span style="display:inline-block; position:relative; max-width:100%; max-height:100%;">
-                <img
-                    node_ref=node_ref
-                    src=move || img_src.get()
-                    alt=alt
-                    class=class
-                    width=width
-                    height=height
-                    style=full_style.clone()
-                    sizes=sizes
-                    srcset=srcset
-                    decoding=decoding.as_str()
-                    crossorigin=crossorigin.as_str()
-                    referrerpolicy=referrerpolicy.as_str()
-                    loading=loading.as_str()
-                    fetchpriority=fetchpriority.as_str()
-                    aria_placeholder=placeholder
-                    on:load=onload
-                    on:error=onerror
-                    role="img"
-                    // aria-label=alt
-                    // aria-labelledby=aria_labelledby
-                    // aria-describedby=aria_describedby
-                    // aria-hidden=aria_hidden
-                    // aria-current=aria_current
-                    // aria-expanded=aria_expanded
-                    // aria-live=aria_live.as_str()
-                    // aria-pressed=aria_pressed.as_str()
-                    // aria-controls=aria_controls
-                    usemap=usemap
-                    ismap=ismap
-                    elementtiming=elementtiming
-                    attributionsrc=attributionsrc
-                />
-            </span>
-        }
-        .into_any(),
+    // The layout is not reactive, only the reserved space follows `width` and `height`.
+    let wrapper_style = attrs.with_untracked(|attrs| attrs.wrapper_style);
+    let img = match wrapper_style {
+        Some(wrapper_style) => view! { <span style=wrapper_style>{img}</span> }.into_any(),
+        None => img.into_any(),
     };
 
     view! {
-        {layout_view}
+        <span style=move || attrs.with(|attrs| attrs.container_style.clone())>
+            {move || {
+                attrs
+                    .with(|attrs| attrs.spacer_style.clone())
+                    .map(|spacer_style| view! { <span style=spacer_style></span> })
+            }}
+            {img}
+        </span>
     }
 }
+
+/// Loads `src` outside of an `<img>` and returns its load status as a signal.
+///
+/// The image goes through the same loader, state machine and fallback chain as [`Image`], so
+/// the status can drive a spinner or a button while the image is rendered elsewhere, or not at
+/// all. A change of `src` cancels the previous load and starts over.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use image_rs::leptos::use_image;
+/// use image_rs::UseImageOptions;
+///
+/// #[component]
+/// pub fn Download() -> impl IntoView {
+///     let status = use_image("/images/photo.jpg", UseImageOptions::default());
+///     view! {
+///         <button disabled=move || !status.get().is_loaded()>"Download"</button>
+///     }
+/// }
+/// ```
+pub fn use_image(src: impl Into<Signal<String>>, options: UseImageOptions) -> Signal<ImageStatus> {
+    let src = src.into();
+    let status = RwSignal::new(ImageStatus::default());
+    let generation = StoredValue::new(0u32);
+
+    Effect::new(move || {
+        let src = src.get();
+        let options = options.clone();
+        let started = generation.try_update_value(|generation| {
+            *generation += 1;
+            *generation
+        });
+        spawn_local(async move {
+            let is_cancelled = || generation.try_get_value() != started;
+            load_image(&src, &options, is_cancelled, |next| status.set(next)).await;
+        });
+    });
+    on_cleanup(move || {
+        generation.try_update_value(|generation| *generation += 1);
+    });
+
+    status.into()
+}
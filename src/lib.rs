@@ -16,7 +16,12 @@ pub mod dioxus;
 #[cfg(feature = "lep")]
 pub mod leptos;
 
+#[cfg(all(feature = "build", not(target_arch = "wasm32")))]
+pub mod build;
+
 pub use common::{
-    AriaLive, AriaPressed, CrossOrigin, Decoding, FetchPriority, Layout, Loading, ObjectFit,
-    Position, ReferrerPolicy,
+    AriaLive, AriaPressed, AspectRatio, Breakpoints, CrossOrigin, Decoding, Dimension, Easing,
+    FallbackEvent, FetchPriority, ImageError, ImageLoader, ImageSource, ImageStatus, Layout,
+    LazyConfig, LoadEvent, Loader, LoaderProps, Loading, ObjectFit, Placeholder, Position,
+    ReferrerPolicy, ScrollRoot, Source, StaticImage, Transition, TransitionKind, UseImageOptions,
 };
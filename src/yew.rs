@@ -1,19 +1,58 @@
 #![doc = include_str!("../YEW.md")]
 
+use crate::common::core::{ImageEvent, ImageOptions, ImageState, LoadEvent};
+use crate::common::decode::decode_image;
+use crate::common::fallback::{FallbackEvent, ImageSource, fallback_chain, resolve_fallback};
+use crate::common::observer::observe_visibility;
+use crate::common::picture::non_empty;
+use crate::common::placeholder::inject_skeleton_keyframes;
+use crate::common::preload::preload_image;
+use crate::common::status::load_image;
+use crate::common::transition::prefers_reduced_motion;
 use crate::common::{
-    AriaLive, AriaPressed, CrossOrigin, Decoding, FetchPriority, Layout, Loading, ObjectFit,
-    Position, ReferrerPolicy,
+    AriaLive, AriaPressed, AspectRatio, Breakpoints, CrossOrigin, Decoding, Dimension,
+    FetchPriority, ImageError, ImageStatus, Layout, LazyConfig, Loader, Loading, ObjectFit,
+    Placeholder, Position, ReferrerPolicy, Source, StaticImage, Transition, UseImageOptions,
 };
-use gloo_net::http::Request;
+use std::cell::Cell;
+use std::rc::Rc;
 use wasm_bindgen_futures::spawn_local;
-use web_sys::IntersectionObserverEntry;
-use web_sys::js_sys;
-use web_sys::wasm_bindgen::JsCast;
-use web_sys::wasm_bindgen::JsValue;
-use web_sys::wasm_bindgen::prelude::*;
-use web_sys::{IntersectionObserver, IntersectionObserverInit, RequestCache};
+use web_sys::HtmlImageElement;
+use yew::html::IntoPropValue;
 use yew::prelude::*;
 
+impl IntoPropValue<Dimension> for &'static str {
+    fn into_prop_value(self) -> Dimension {
+        Dimension::from(self)
+    }
+}
+
+impl IntoPropValue<Dimension> for String {
+    fn into_prop_value(self) -> Dimension {
+        Dimension::from(self)
+    }
+}
+
+impl IntoPropValue<Dimension> for AttrValue {
+    fn into_prop_value(self) -> Dimension {
+        Dimension::from(self.as_str())
+    }
+}
+
+impl IntoPropValue<Dimension> for u32 {
+    fn into_prop_value(self) -> Dimension {
+        Dimension::from(self)
+    }
+}
+
+impl Reducible for ImageState {
+    type Action = ImageEvent;
+
+    fn reduce(self: Rc<Self>, event: Self::Action) -> Rc<Self> {
+        Rc::new(self.next(event))
+    }
+}
+
 /// Properties for the `Image` component.
 ///
 /// The `Image` component allows you to display an image with various customization options
@@ -32,63 +71,78 @@ pub struct ImageProps {
     /// This is the URL of the image to be displayed. This property is required for loading
     /// an image. If not provided, the image will not be displayed.
     #[prop_or_default]
-    pub src: &'static str,
+    pub src: AttrValue,
 
     /// The alternative text for the image.
     ///
     /// This is the alt text for the image, which is used for accessibility purposes.
     /// If not provided, the alt text will be empty.
     #[prop_or_default]
-    pub alt: &'static str,
+    pub alt: AttrValue,
 
     /// Optional fallback image.
     ///
     /// This image will be displayed if the main image fails to load. If not provided,
     /// the image will attempt to load without a fallback.
     #[prop_or_default]
-    pub fallback_src: &'static str,
+    pub fallback_src: AttrValue,
+
+    /// Additional fallback images, tried in order after `fallback_src`.
+    ///
+    /// Each source has its own retry count, exponential backoff and per-attempt timeout.
+    /// Defaults to an empty list.
+    #[prop_or_default]
+    pub fallbacks: Vec<ImageSource>,
+
+    /// Callback reporting every step of the fallback chain.
+    ///
+    /// Emits a `FallbackEvent` for each retry, each source given up on and the source that
+    /// finally replaces the image. Defaults to a no-op.
+    #[prop_or_default]
+    pub on_fallback: Callback<FallbackEvent>,
 
     /// The width of the image.
     ///
-    /// Specifies the width of the image in pixels. It is typically used for responsive
-    /// layouts. Defaults to an empty string if not provided.
+    /// A `Dimension`, also accepted as a string such as `"800"` or `"50%"` or as a number of
+    /// pixels. It is typically used for responsive layouts. Defaults to `Dimension::Auto`.
     #[prop_or_default]
-    pub width: &'static str,
+    pub width: Dimension,
 
     /// The height of the image.
     ///
-    /// Specifies the height of the image in pixels. Like `width`, it is often used for
-    /// responsive layouts. Defaults to an empty string if not provided.
+    /// A `Dimension`, like `width`, often used for responsive layouts. Defaults to
+    /// `Dimension::Auto`.
     #[prop_or_default]
-    pub height: &'static str,
+    pub height: Dimension,
 
     // Common props
     /// The style attribute for the image.
     ///
     /// Allows you to apply custom inline CSS styles to the image. Defaults to an empty string.
     #[prop_or_default]
-    pub style: &'static str,
+    pub style: AttrValue,
 
     /// The CSS class for the image.
     ///
     /// This can be used to apply custom CSS classes to the image for styling purposes.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub class: &'static str,
+    pub class: AttrValue,
 
     /// The sizes attribute for the image.
     ///
     /// This is used to define different image sizes for different viewport widths, helping
     /// with responsive images. Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub sizes: &'static str,
+    pub sizes: AttrValue,
 
     /// The quality attribute for the image.
     ///
-    /// Allows you to set the quality of the image (e.g., "low", "medium", "high"). Defaults
-    /// to an empty string if not provided.
+    /// Allows you to set the quality of the image, either as a number from 1 to 100 or as a
+    /// hint ("low", "medium", "high"). It is forwarded to the `loader`. Defaults to an empty
+    /// string, which lets the loader pick its own quality.
     #[prop_or_default]
-    pub quality: &'static str,
+    pub quality: AttrValue,
 
     /// Indicates if the image should have priority loading.
     ///
@@ -97,20 +151,57 @@ pub struct ImageProps {
     #[prop_or_default]
     pub loading: Loading,
 
-    /// The placeholder attribute for the image.
+    /// Marks the image as high priority, e.g. the hero image of a page.
+    ///
+    /// Priority images are loaded eagerly with `fetchpriority="high"`, skip the lazy-loading
+    /// observer and are preloaded with a `<link rel="preload">` injected into the document head.
+    /// Use it for images visible above the fold to improve LCP. Defaults to `false`.
+    #[prop_or_default]
+    pub priority: bool,
+
+    /// What is rendered behind the image until it is decoded.
+    ///
+    /// A blurred low-quality image, a color, a thumbnail, a BlurHash or ThumbHash, a skeleton,
+    /// or any `Html` with `Placeholder::Custom`. Defaults to `Placeholder::Empty`.
+    #[prop_or_default]
+    pub placeholder: Placeholder<Html>,
+
+    /// A bundled image, usually built by the `static_image!` macro.
+    ///
+    /// Provides the `src`, the `width` and `height` and a blur placeholder when they are not
+    /// set, so responsive images reserve their exact space. Defaults to `None`.
+    #[prop_or_default]
+    pub static_image: Option<StaticImage>,
+
+    /// The aspect ratio reserving the space of the image before it loads.
     ///
-    /// Allows you to specify a placeholder image URL or data URL to show while the main
-    /// image is loading. Defaults to an empty string.
+    /// Used by `Layout::Responsive` and `Layout::AspectRatio`. Defaults to the ratio of
+    /// `width` and `height`, then to the one of a ThumbHash placeholder, then to a square.
     #[prop_or_default]
-    pub placeholder: &'static str,
+    pub aspect_ratio: Option<AspectRatio>,
 
     /// Callback function for handling loading completion.
     ///
-    /// This callback is triggered once the image has finished loading. This is useful for
-    /// actions that should happen after the image has been fully loaded, such as hiding
-    /// a loading spinner. Defaults to a no-op.
+    /// This callback is triggered once the image, or the fallback replacing it, has been
+    /// decoded, with its URL and natural size. It fires once per source, even when the
+    /// browser picks another `srcset` candidate later on. This is useful for actions that
+    /// should happen after the image has been fully loaded, such as hiding a loading spinner.
+    /// Defaults to a no-op.
     #[prop_or_default]
-    pub on_load: Callback<()>,
+    pub on_load: Callback<LoadEvent>,
+
+    /// Callback function fired with the `src` when a lazy image enters the viewport.
+    ///
+    /// Fires once per `src`, never for eager or priority images. Defaults to a no-op.
+    #[prop_or_default]
+    pub on_visible: Callback<AttrValue>,
+
+    /// Callback function fired with the `src` when the browser is allowed to request it.
+    ///
+    /// Fires once per `src`: on mount for eager images, once visible for lazy ones. Defaults
+    /// to a no-op.
+    #[prop_or_default]
+    pub on_load_start: Callback<AttrValue>,
 
     // Advanced Props
     /// The object-fit attribute for the image.
@@ -143,7 +234,7 @@ pub struct ImageProps {
     /// This callback is triggered if the image fails to load, allowing you to handle
     /// error states (e.g., displaying a fallback image or showing an error message).
     #[prop_or_default]
-    pub on_error: Callback<String>,
+    pub on_error: Callback<ImageError>,
 
     /// The decoding attribute for the image.
     ///
@@ -151,37 +242,58 @@ pub struct ImageProps {
     /// - `Decoding::Auto`: The image decoding behavior is automatically decided by the browser.
     /// - `Decoding::Sync`: The image is decoded synchronously (blocking other tasks).
     /// - `Decoding::Async`: The image is decoded asynchronously (non-blocking).
+    /// - `Decoding::Deferred`: The image is decoded off-DOM and only revealed, without the
+    ///   blur placeholder, once decoded.
     ///
     /// Defaults to `Decoding::Auto`.
     #[prop_or_default]
     pub decoding: Decoding,
 
-    /// The blur data URL for placeholder image.
+    /// The transition between the placeholder and the decoded image.
+    ///
+    /// Fades render the placeholder in its own layer behind the image and remove it once
+    /// the transition ends. Skipped when the user prefers reduced motion.
     ///
-    /// This is used to display a low-quality blurred version of the image while the full
-    /// image is loading. Defaults to an empty string.
+    /// Defaults to no transition.
     #[prop_or_default]
-    pub blur_data_url: &'static str,
+    pub transition: Transition,
 
     /// The lazy boundary for lazy loading.
     ///
     /// Defines the distance (in pixels) from the viewport at which the image should start
-    /// loading. Defaults to an empty string.
+    /// loading. Defaults to `"100px"`.
+    #[prop_or(AttrValue::Static("100px"))]
+    pub lazy_boundary: AttrValue,
+
+    /// The scroll root, margin and thresholds used to lazy load the image.
+    ///
+    /// Use it for images inside a scrolling container such as a carousel or a modal, so they
+    /// start loading before they scroll into it. Takes precedence over `lazy_boundary` when set.
     #[prop_or_default]
-    pub lazy_boundary: &'static str,
+    pub lazy_config: Option<LazyConfig>,
 
     /// Indicates if the image should be unoptimized.
     ///
     /// If set to `true`, the image will be loaded without any optimization applied (e.g.,
-    /// no resizing or compression). Defaults to `false`.
+    /// no resizing or compression). The `loader` is skipped and `src` is used as is.
+    /// Defaults to `false`.
     #[prop_or_default]
     pub unoptimized: bool,
 
+    /// Loader used to build the final image URL.
+    ///
+    /// Receives the `src`, the `width` and the `quality` of the image and returns the URL
+    /// to fetch, e.g. a resized variant served by an image CDN. When not provided, `src`
+    /// is used as is.
+    #[prop_or_default]
+    pub loader: Option<Loader>,
+
     /// Image layout.
     ///
     /// Specifies how the image should be laid out within its container. Possible values
     /// include `Layout::Fill`, `Layout::Responsive`, `Layout::Intrinsic`, `Layout::Fixed`,
-    /// `Layout::Auto`, `Layout::Stretch`, and `Layout::ScaleDown`. Defaults to `Layout::Responsive`.
+    /// `Layout::Auto`, `Layout::Stretch`, `Layout::ScaleDown`, and `Layout::AspectRatio`.
+    /// Defaults to `Layout::Responsive`.
     #[prop_or_default]
     pub layout: Layout,
 
@@ -196,8 +308,17 @@ pub struct ImageProps {
     ///
     /// Defines multiple image resources for the browser to choose from, depending on screen size, resolution,
     /// and other factors. Each source can include width (`w`) or pixel density (`x`) descriptors.
+    /// When empty and a `loader` is set, the `srcset` is generated from `breakpoints`.
+    #[prop_or_default]
+    pub srcset: AttrValue,
+
+    /// Widths used to generate the `srcset` when a `loader` is set.
+    ///
+    /// `Layout::Responsive`, `Layout::Fill` and `Layout::Stretch` images get width descriptors
+    /// built from the device widths, other layouts get 1x/2x density descriptors based on `width`.
+    /// Defaults to the Next.js device and image widths.
     #[prop_or_default]
-    pub srcset: &'static str,
+    pub breakpoints: Breakpoints,
 
     /// Cross-origin policy to use when fetching the image.
     ///
@@ -218,7 +339,7 @@ pub struct ImageProps {
     /// Associates the image with a `<map>` element, enabling clickable regions within the image. The value
     /// should begin with `#` and match the `name` of the corresponding map element.
     #[prop_or_default]
-    pub usemap: &'static str,
+    pub usemap: AttrValue,
 
     /// Indicates that the image is part of a server-side image map.
     ///
@@ -239,41 +360,41 @@ pub struct ImageProps {
     /// Registers the image with the `PerformanceElementTiming` API using the given string as its ID. Useful for
     /// performance monitoring and analytics.
     #[prop_or_default]
-    pub elementtiming: &'static str,
+    pub elementtiming: AttrValue,
 
     /// URL(s) to send Attribution Reporting requests for the image.
     ///
     /// Indicates that the browser should send an `Attribution-Reporting-Eligible` header with the image request.
     /// Can be a boolean or a list of URLs for attribution registration on specified servers. Experimental feature.
     #[prop_or_default]
-    pub attributionsrc: &'static str,
+    pub attributionsrc: AttrValue,
 
     /// Indicates the current state of the image in a navigation menu.
     ///
     /// Valid values are "page", "step", "location", "date", "time", "true", "false".
     /// This is useful for enhancing accessibility in navigation menus.
     #[prop_or_default]
-    pub aria_current: &'static str,
+    pub aria_current: AttrValue,
 
     /// Describes the image using the ID of the element that provides a description.
     ///
     /// The ID of the element that describes the image. This is used for accessibility
     /// purposes, particularly for screen readers.
     #[prop_or_default]
-    pub aria_describedby: &'static str,
+    pub aria_describedby: AttrValue,
 
     /// Indicates whether the content associated with the image is currently expanded or collapsed.
     ///
     /// This is typically used for ARIA-based accessibility and is represented as "true", "false", or "undefined".
     #[prop_or_default]
-    pub aria_expanded: &'static str,
+    pub aria_expanded: AttrValue,
 
     /// Indicates whether the image is currently hidden from the user.
     ///
     /// This attribute is used for accessibility and indicates whether the image is visible
     /// to the user or not. Valid values are "true", "false", or "undefined".
     #[prop_or_default]
-    pub aria_hidden: &'static str,
+    pub aria_hidden: AttrValue,
 
     /// Indicates whether the content associated with the image is live and dynamic.
     ///
@@ -292,55 +413,65 @@ pub struct ImageProps {
     ///
     /// Specifies the ID of the element that the image controls or is associated with.
     #[prop_or_default]
-    pub aria_controls: &'static str,
+    pub aria_controls: AttrValue,
 
     /// ID of the element that labels the image.
     ///
     /// Specifies the ID of the element that labels the image for accessibility purposes.
     #[prop_or_default]
-    pub aria_labelledby: &'static str,
+    pub aria_labelledby: AttrValue,
 }
 
 impl Default for ImageProps {
     fn default() -> Self {
         ImageProps {
-            src: "",
-            alt: "Image",
-            width: "",
-            height: "",
-            style: "",
-            class: "",
-            sizes: "",
-            quality: "",
-            placeholder: "empty",
+            src: AttrValue::Static(""),
+            alt: AttrValue::Static("Image"),
+            width: Dimension::Auto,
+            height: Dimension::Auto,
+            style: AttrValue::Static(""),
+            class: AttrValue::Static(""),
+            sizes: AttrValue::Static(""),
+            quality: AttrValue::Static(""),
+            placeholder: Placeholder::Empty,
+            static_image: None,
+            aspect_ratio: None,
             on_load: Callback::noop(),
+            on_visible: Callback::noop(),
+            on_load_start: Callback::noop(),
             object_fit: ObjectFit::default(),
             object_position: Position::default(),
             on_error: Callback::noop(),
             decoding: Decoding::default(),
-            blur_data_url: "",
-            lazy_boundary: "100px",
+            transition: Transition::default(),
+            lazy_boundary: AttrValue::Static("100px"),
+            lazy_config: None,
             unoptimized: false,
+            loader: None,
             layout: Layout::default(),
             node_ref: NodeRef::default(),
-            fallback_src: "",
-            srcset: "",
+            fallback_src: AttrValue::Static(""),
+            fallbacks: Vec::new(),
+            on_fallback: Callback::noop(),
+            srcset: AttrValue::Static(""),
+            breakpoints: Breakpoints::default(),
             crossorigin: CrossOrigin::default(),
             loading: Loading::default(),
+            priority: false,
             referrerpolicy: ReferrerPolicy::default(),
-            usemap: "",
+            usemap: AttrValue::Static(""),
             ismap: false,
             fetchpriority: FetchPriority::default(),
-            elementtiming: "",
-            attributionsrc: "",
-            aria_current: "",
-            aria_describedby: "",
-            aria_expanded: "",
-            aria_hidden: "",
+            elementtiming: AttrValue::Static(""),
+            attributionsrc: AttrValue::Static(""),
+            aria_current: AttrValue::Static(""),
+            aria_describedby: AttrValue::Static(""),
+            aria_expanded: AttrValue::Static(""),
+            aria_hidden: AttrValue::Static(""),
             aria_live: AriaLive::default(),
             aria_pressed: AriaPressed::default(),
-            aria_controls: "",
-            aria_labelledby: "",
+            aria_controls: AttrValue::Static(""),
+            aria_labelledby: AttrValue::Static(""),
         }
     }
 }
@@ -353,24 +484,31 @@ impl Default for ImageProps {
 /// # Properties
 /// The component uses the `ImageProps` struct for its properties. Key properties include:
 ///
-/// - **src**: The main image source URL (`&'static str`). Required.
-/// - **alt**: Alternative text for accessibility (`&'static str`). Default: `""`.
+/// - **src**: The main image source URL (`AttrValue`). Required.
+/// - **alt**: Alternative text for accessibility (`AttrValue`). Default: `""`.
 /// - **layout**: The image layout strategy (`Layout`). Default: `Layout::Auto`.
-/// - **width**: The width of the image (`&'static str`). Required for certain layouts.
-/// - **height**: The height of the image (`&'static str`). Required for certain layouts.
-/// - **sizes**: Defines responsive image sizes (`&'static str`). Default: `""`.
-/// - **quality**: Image quality (custom property) (`&'static str`). Optional.
-/// - **placeholder**: Placeholder strategy before the image loads (e.g., `"blur"`) (`&'static str`). Default: `""`.
-/// - **blur_data_url**: Base64-encoded low-res placeholder image (`&'static str`). Used when `placeholder` is `"blur"`.
-/// - **fallback_src**: Fallback image URL if the main `src` fails to load (`&'static str`). Optional.
-/// - **priority**: Whether to load the image eagerly instead of lazily (`bool`). Default: `false`.
+/// - **width**: The width of the image (`AttrValue`). Required for certain layouts.
+/// - **height**: The height of the image (`AttrValue`). Required for certain layouts.
+/// - **sizes**: Defines responsive image sizes (`AttrValue`). Default: `""`, or `"100vw"` for generated width descriptors.
+/// - **breakpoints**: Device and image widths used to generate the `srcset` (`Breakpoints`). Default: Next.js widths.
+/// - **quality**: Image quality forwarded to the loader (`AttrValue`). Optional.
+/// - **loader**: Builds the final image URL from `src`, `width` and `quality` (`Option<Loader>`). Optional.
+/// - **unoptimized**: Skips the loader and uses `src` as is (`bool`). Default: `false`.
+/// - **placeholder**: What is rendered behind the image until it is decoded (`Placeholder<Html>`). Default: `Placeholder::Empty`.
+/// - **fallback_src**: Fallback image URL if the main `src` fails to load (`AttrValue`). Optional.
+/// - **fallbacks**: Additional fallback images tried in order, with retries and backoff (`Vec<ImageSource>`). Default: empty.
+/// - **on_fallback**: Callback reporting each step of the fallback chain (`Callback<FallbackEvent>`). Default: no-op.
+/// - **priority**: Loads the image eagerly with a high fetch priority and preloads it from the document head (`bool`). Default: `false`.
 /// - **object_fit**: CSS `object-fit` value (`ObjectFit`). Default: `ObjectFit::Contain`.
 /// - **object_position**: Object positioning inside the container (`Position`). Default: `Position::Center`.
-/// - **style**: Additional inline CSS styles (`&'static str`). Default: `""`.
-/// - **class**: Additional CSS classes (`&'static str`). Default: `""`.
+/// - **style**: Additional inline CSS styles (`AttrValue`). Default: `""`.
+/// - **class**: Additional CSS classes (`AttrValue`). Default: `""`.
 /// - **decoding**: Decoding strategy (`Decoding`). Default: `Decoding::Auto`.
-/// - **on_load**: Callback invoked when the image successfully loads (`Callback<()>`). Default: no-op.
-/// - **on_error**: Callback invoked if loading or fallback loading fails (`Callback<String>`). Default: no-op.
+/// - **transition**: Transition between the placeholder and the decoded image (`Transition`). Default: none.
+/// - **on_visible**: Callback invoked when a lazy image enters the viewport (`Callback<AttrValue>`). Default: no-op.
+/// - **on_load_start**: Callback invoked when the image starts loading (`Callback<AttrValue>`). Default: no-op.
+/// - **on_load**: Callback invoked once the image or its fallback is decoded (`Callback<LoadEvent>`). Default: no-op.
+/// - **on_error**: Callback invoked if loading or fallback loading fails (`Callback<ImageError>`). Default: no-op.
 /// - **node_ref**: Node reference for the underlying `img` element (`NodeRef`).
 /// - **ARIA attributes**: Full ARIA support for better accessibility (`aria_label`, `aria_hidden`, etc.).
 ///
@@ -385,7 +523,7 @@ impl Default for ImageProps {
 ///   Supports blurred low-resolution placeholders for smoother image transitions.
 ///
 /// - **Multiple Layouts**:
-///   Supports various layouts like `Fill`, `Responsive`, `Intrinsic`, `Fixed`, `Auto`, `Stretch`, and `ScaleDown`.
+///   Supports various layouts like `Fill`, `Responsive`, `Intrinsic`, `Fixed`, `Auto`, `Stretch`, `ScaleDown`, and `AspectRatio`.
 ///
 /// - **Accessibility**:
 ///   Built-in support for ARIA attributes to make images fully accessible.
@@ -398,6 +536,7 @@ impl Default for ImageProps {
 /// - `Layout::Auto`: Default natural behavior without forcing constraints.
 /// - `Layout::Stretch`: Fills the parent container's width and height.
 /// - `Layout::ScaleDown`: Scales image down to fit the container without stretching.
+/// - `Layout::AspectRatio`: Fills the container's width, with a height set by `aspect_ratio`.
 ///
 /// # Examples
 ///
@@ -425,7 +564,7 @@ impl Default for ImageProps {
 /// ```rust
 /// use yew::prelude::*;
 /// use image_rs::yew::Image;
-/// use image_rs::Layout;
+/// use image_rs::{Layout, Placeholder};
 ///
 /// #[function_component(App)]
 /// pub fn app() -> Html {
@@ -436,8 +575,7 @@ impl Default for ImageProps {
 ///             width="800"
 ///             height="600"
 ///             layout={Layout::Intrinsic}
-///             placeholder="blur"
-///             blur_data_url="data:image/jpeg;base64,..."
+///             placeholder={Placeholder::Blur("data:image/jpeg;base64,...".into())}
 ///         />
 ///     }
 /// }
@@ -466,7 +604,8 @@ impl Default for ImageProps {
 ///
 /// # Behavior
 /// - The image starts loading lazily once it enters the viewport (10% visible threshold).
-/// - If loading fails, a network fetch checks the fallback image.
+/// - If loading fails, `fallback_src` then `fallbacks` are checked in order with a network fetch, each
+///   retried with exponential backoff on transient errors.
 /// - Blur placeholder is rendered with a heavy `blur(20px)` effect until the full image loads.
 /// - Depending on the `Layout`, the container styling adjusts automatically.
 ///
@@ -474,11 +613,12 @@ impl Default for ImageProps {
 /// - `width` and `height` are required for `Responsive`, `Intrinsic`, and `Fixed` layouts.
 /// - `Layout::Fill` ignores width and height, stretching to fit the parent container.
 /// - Accessibility attributes like `aria-label` and `aria-hidden` are passed directly to the `<img>` element.
-/// - Priority images (`priority = true`) are loaded eagerly instead of lazily.
+/// - Priority images (`priority = true`) are loaded eagerly with `fetchpriority="high"`, skip the
+///   IntersectionObserver and get a deduplicated `<link rel="preload">` in the document head.
 ///
 /// # Errors
-/// - If both `src` and `fallback_src` fail, the `on_error` callback is triggered with an error message.
-/// - JSON parsing from a fallback response is attempted but not mandatory for image loading success.
+/// - If `src` and every fallback fail, the `on_error` callback is triggered with an [`ImageError`].
+/// - The fallback is only swapped in once a network request confirms it can be fetched.
 ///
 /// # Optimization Techniques
 /// - **IntersectionObserver** is used for intelligent lazy loading.
@@ -489,438 +629,457 @@ impl Default for ImageProps {
 /// - [MDN img Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/img)
 #[function_component]
 pub fn Image(props: &ImageProps) -> Html {
-    let mut props = props.clone();
-    let img_ref = props.node_ref.clone();
+    use_image_html(props, &[])
+}
 
-    let img_ref_clone = img_ref.clone();
-    let on_load = props.on_load.clone();
-    let on_load_call = props.on_load.clone();
+/// Properties for the [`Picture`] component.
+#[derive(Properties, Clone, PartialEq)]
+pub struct PictureProps {
+    /// The `<source>` elements, tried in order before the `<img>`.
+    ///
+    /// Serves other formats, e.g. AVIF and WebP with a JPEG `src`, or other crops per
+    /// breakpoint with a `media` query.
+    #[prop_or_default]
+    pub sources: Vec<Source>,
 
-    // Lazy Load Effect:
-    // Waits until the image **scrolls into view**, then dynamically **sets the src** to start loading it.
-    // Triggers an optional `on_load` callback once loading is initiated.
-    // Smart Optimization: Saves bandwidth and greatly improves page speed, especially for pages with **many images**!
-    // 9000 IQ Move: Only load images users actually *scroll to*, no more wasting bytes, gg!
-    use_effect_with(JsValue::from(props.src), move |_deps| {
-        let callback = Closure::wrap(Box::new(
-            move |entries: js_sys::Array, _observer: IntersectionObserver| {
-                if let Some(entry) = entries.get(0).dyn_ref::<IntersectionObserverEntry>() {
-                    if entry.is_intersecting() {
-                        if let Some(img) = img_ref_clone.cast::<web_sys::HtmlImageElement>() {
-                            img.set_src(props.src);
-                            on_load.emit(());
-                        }
-                    }
-                }
-            },
-        )
-            as Box<dyn FnMut(js_sys::Array, IntersectionObserver)>);
-
-        let options = IntersectionObserverInit::new();
-        // e.g., 10% visible
-        options.set_threshold(&js_sys::Array::of1(&0.1.into()));
-        // if Root is None, defaults to viewport
-
-        // Create observer
-        let observer =
-            IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &options)
-                .expect("Failed to create IntersectionObserver");
-
-        // Start observing
-        if let Some(img) = img_ref.cast::<web_sys::HtmlElement>() {
-            observer.observe(&img);
-        }
+    /// The properties of the inner `<img>`, with the same behavior as an [`Image`].
+    #[prop_or_default]
+    pub image: ImageProps,
+}
+
+/// A `<picture>` with `<source>` elements for other formats and art direction.
+///
+/// The inner `<img>` is an [`Image`]: it is lazy loaded, shows the placeholder and goes
+/// through the fallback chain the same way. The sources are only rendered once the image is
+/// in view, and dropped once a fallback is displayed.
+///
+/// # Example
+/// ```rust
+/// use yew::prelude::*;
+/// use image_rs::yew::{ImageProps, Picture};
+/// use image_rs::{Layout, Source};
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let sources = vec![
+///         Source::avif("/images/photo.avif"),
+///         Source::webp("/images/photo.webp"),
+///         Source::new("/images/photo-square.jpg").with_media("(max-width: 640px)"),
+///     ];
+///     html! {
+///         <Picture
+///             sources={sources}
+///             image={ImageProps {
+///                 src: "/images/photo.jpg".into(),
+///                 alt: "A beautiful view".into(),
+///                 width: 800.into(),
+///                 height: 600.into(),
+///                 layout: Layout::Responsive,
+///                 ..Default::default()
+///             }}
+///         />
+///     }
+/// }
+/// ```
+///
+/// # Notes
+/// - `Decoding::Deferred` falls back to `Decoding::Async`, the browser picking the source.
+/// - Priority images get `fetchpriority="high"` but no `<link rel="preload">`, which could
+///   fetch a source the browser does not pick.
+///
+/// # See Also
+/// - [MDN picture Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/picture)
+#[function_component]
+pub fn Picture(props: &PictureProps) -> Html {
+    use_image_html(&props.image, &props.sources)
+}
+
+/// Renders an [`Image`], wrapped in a `<picture>` when it has `sources`.
+#[hook]
+fn use_image_html(props: &ImageProps, sources: &[Source]) -> Html {
+    let reduced_motion = *use_memo((), |_| prefers_reduced_motion());
+    let options = ImageOptions {
+        src: &props.src,
+        width: props.width,
+        height: props.height,
+        quality: &props.quality,
+        sizes: &props.sizes,
+        srcset: &props.srcset,
+        sources,
+        style: &props.style,
+        placeholder: &props.placeholder,
+        static_image: props.static_image,
+        aspect_ratio: props.aspect_ratio,
+        layout: props.layout,
+        object_fit: props.object_fit,
+        object_position: props.object_position,
+        loading: props.loading.clone(),
+        fetchpriority: props.fetchpriority.clone(),
+        decoding: props.decoding,
+        transition: props.transition,
+        reduced_motion,
+        priority: props.priority,
+        loader: props.loader.as_ref(),
+        breakpoints: &props.breakpoints,
+        unoptimized: props.unoptimized,
+    };
+    let lazy = options.is_lazy();
+    let attrs = options.attributes();
+
+    let state = use_reducer(|| ImageState::initial(lazy));
+    // Index of the fallback of the chain currently displayed, if any.
+    let fallback = use_state(|| None::<usize>);
+    // Whether the transition revealing the decoded image ended.
+    let transitioned = use_state(|| false);
+    // The `src` the state belongs to, and a counter cancelling the fallback work of older ones.
+    let loaded_src = use_mut_ref(|| None::<String>);
+    let generation = use_mut_ref(|| 0u32);
+
+    // A new `src` renders from the initial state until the reset below is applied.
+    let is_current = loaded_src
+        .borrow()
+        .as_deref()
+        .is_none_or(|src| src == attrs.src);
+    let (current, fallback_index) = if is_current {
+        (*state, *fallback)
+    } else {
+        (ImageState::initial(lazy), None)
+    };
+
+    let chain = Rc::new(fallback_chain(&props.fallback_src, &props.fallbacks));
+    let fallback_src = fallback_index
+        .and_then(|index| chain.get(index))
+        .map(|source| source.src.as_str());
 
-        // Disconnect when unmount
-        let observer_clone = observer.clone();
-        let _cleanup = move || {
-            observer_clone.disconnect();
-        };
+    // Starts over when `src` changes: resets the state, re-arms lazy loading and cancels any
+    // fallback still being resolved for the previous `src`.
+    {
+        let state = state.clone();
+        let fallback = fallback.clone();
+        let transitioned = transitioned.clone();
+        let loaded_src = loaded_src.clone();
+        let generation = generation.clone();
+        let on_load_start = props.on_load_start.clone();
+        use_effect_with(attrs.src.clone(), move |src| {
+            let previous = loaded_src.borrow_mut().replace(src.clone());
+            let changed = previous.as_ref().is_some_and(|previous| previous != src);
+            if changed {
+                *generation.borrow_mut() += 1;
+                state.dispatch(ImageEvent::Reset { lazy });
+                fallback.set(None);
+                transitioned.set(false);
+            }
+            // Lazy images start loading once visible.
+            if (previous.is_none() || changed) && !lazy {
+                on_load_start.emit(AttrValue::from(src.clone()));
+            }
+        });
+    }
+
+    // Unmounting cancels any fallback still being resolved, so no callback fires afterwards.
+    {
+        let generation = generation.clone();
+        use_effect_with((), move |_| move || *generation.borrow_mut() += 1);
+    }
 
-        // Keep closure alive
-        callback.forget();
+    use_effect_with(props.placeholder.is_skeleton(), |skeleton| {
+        if *skeleton {
+            inject_skeleton_keyframes();
+        }
     });
 
+    // Priority images are requested as early as possible through a preload hint in the head.
+    // Not those of a `<picture>`, whose preload could fetch a source the browser does not pick.
+    {
+        let crossorigin = props.crossorigin.clone();
+        let referrerpolicy = props.referrerpolicy;
+        use_effect_with(
+            (
+                props.priority && attrs.sources.is_empty(),
+                attrs.src.clone(),
+                attrs.srcset.clone(),
+                attrs.sizes.clone(),
+            ),
+            move |(priority, src, srcset, sizes)| {
+                if *priority {
+                    preload_image(src, srcset, sizes, &crossorigin, referrerpolicy);
+                }
+            },
+        );
+    }
+
+    // Lazy Load Effect:
+    // Waits until the image **scrolls into view**, then lets the state machine **render the src** to start loading it.
+    // Smart Optimization: Saves bandwidth and greatly improves page speed, especially for pages with **many images**!
+    // 9000 IQ Move: Only load images users actually *scroll to*, no more wasting bytes, gg!
+    {
+        let state = state.clone();
+        let img_ref = props.node_ref.clone();
+        let on_visible = props.on_visible.clone();
+        let on_load_start = props.on_load_start.clone();
+        let lazy_config = props
+            .lazy_config
+            .clone()
+            .unwrap_or_else(|| LazyConfig::from_boundary(&props.lazy_boundary));
+        let idle = current == ImageState::Idle;
+        let deps = (attrs.src.clone(), lazy, idle, lazy_config);
+        use_effect_with(deps, move |(src, lazy, idle, lazy_config)| {
+            let src = AttrValue::from(src.clone());
+            let observation = img_ref
+                .cast::<web_sys::Element>()
+                .filter(|_| *lazy && *idle)
+                .and_then(|img| {
+                    observe_visibility(&img, lazy_config, move || {
+                        state.dispatch(ImageEvent::Visible);
+                        on_visible.emit(src.clone());
+                        state.dispatch(ImageEvent::LoadStart);
+                        on_load_start.emit(src);
+                    })
+                });
+            move || drop(observation)
+        });
+    }
+
     // This informs your app that the image failed to load and auto replace the image.
-    let fetch_data = {
-        Callback::from(move |_| {
-            let loading_complete_callback = props.on_load.clone();
-            let on_error_callback = props.on_error.clone();
+    let fail = {
+        let state = state.clone();
+        let fallback = fallback.clone();
+        let generation = generation.clone();
+        let chain = chain.clone();
+        let on_error = props.on_error.clone();
+        let on_fallback = props.on_fallback.clone();
+        let load_error = attrs.load_error(fallback_src);
+        Callback::from(move |()| {
+            if current.is_settled() {
+                return;
+            }
+            // A displayed fallback failing moves on to the next one of the chain.
+            if let Some(source) = fallback_index {
+                on_fallback.emit(FallbackEvent::Skipped {
+                    source,
+                    error: load_error.clone(),
+                });
+            }
+            let start = fallback_index.map_or(0, |index| index + 1);
+            let has_fallback = start < chain.len();
+            state.dispatch(ImageEvent::Error { has_fallback });
+            if !has_fallback {
+                on_error.emit(load_error.clone());
+                return;
+            }
+            let state = state.clone();
+            let fallback = fallback.clone();
+            let chain = chain.clone();
+            let on_error = on_error.clone();
+            let on_fallback = on_fallback.clone();
+            let generation = generation.clone();
+            let spawned = *generation.borrow();
             spawn_local(async move {
-                match Request::get(props.fallback_src)
-                    .cache(RequestCache::Reload)
-                    .send()
+                let is_cancelled = || *generation.borrow() != spawned;
+                match resolve_fallback(&chain, start, is_cancelled, |step| on_fallback.emit(step))
                     .await
                 {
-                    Ok(response) => {
-                        if response.status() == 200 {
-                            let json_result = response.json::<serde_json::Value>();
-                            match json_result.await {
-                                Ok(_data) => {
-                                    props.src = props.fallback_src;
-                                    loading_complete_callback.emit(());
-                                }
-                                Err(_err) => {
-                                    on_error_callback.emit("Image Not Found!".to_string());
-                                }
-                            }
-                        } else {
-                            let status = response.status();
-                            let body = response.text().await.unwrap_or_else(|_| {
-                                String::from("Failed to retrieve response body")
-                            });
-                            on_error_callback.emit(format!(
-                                "Failed to load image. Status: {}, Body: {:?}",
-                                status, body
-                            ));
-                        }
-                    }
-
+                    Ok((index, _)) => fallback.set(Some(index)),
+                    // The `src` changed, the previous image no longer matters.
+                    Err(ImageError::Aborted { .. }) => {}
                     Err(err) => {
-                        // Handle network errors
-                        on_error_callback.emit(format!("Network error: {}", err));
+                        state.dispatch(ImageEvent::Error {
+                            has_fallback: false,
+                        });
+                        on_error.emit(err);
                     }
                 }
             });
         })
     };
+    let onerror = fail.reform(|_: Event| ());
 
-    let img_style = {
-        let mut style = String::new();
-        style.push_str(&format!("object-fit: {};", props.object_fit.as_str()));
-        style.push_str(&format!(
-            "object-position: {};",
-            props.object_position.as_str()
-        ));
-        if !props.style.is_empty() {
-            style.push_str(props.style);
-        }
-        style
-    };
-
-    let blur_style = if props.placeholder == "blur" {
-        format!(
-            "background-size: {}; background-position: {}; filter: blur(20px); background-image: url(\"{}\")",
-            props.sizes,
-            props.object_position.as_str(),
-            props.blur_data_url
-        )
-    } else {
-        String::new()
-    };
+    // Deferred images are fetched and decoded off-DOM, then revealed with the blur removed.
+    {
+        let state = state.clone();
+        let generation = generation.clone();
+        let on_load = props.on_load.clone();
+        let crossorigin = props.crossorigin.clone();
+        let referrerpolicy = props.referrerpolicy;
+        let deps = (
+            attrs.awaits_decode(current, fallback_src),
+            attrs.src.clone(),
+            attrs.srcset.clone(),
+            attrs.sizes.clone(),
+        );
+        use_effect_with(deps, move |(awaits_decode, src, srcset, sizes)| {
+            let cancelled = Rc::new(Cell::new(false));
+            if *awaits_decode {
+                let (src, srcset, sizes) = (src.clone(), srcset.clone(), sizes.clone());
+                let spawned = *generation.borrow();
+                let cancelled = cancelled.clone();
+                spawn_local(async move {
+                    let result =
+                        decode_image(&src, &srcset, &sizes, &crossorigin, referrerpolicy).await;
+                    if cancelled.get() || *generation.borrow() != spawned {
+                        return;
+                    }
+                    match result {
+                        Ok(load) => {
+                            state.dispatch(ImageEvent::Load);
+                            on_load.emit(load);
+                        }
+                        Err(_) => fail.emit(()),
+                    }
+                });
+            }
+            move || cancelled.set(true)
+        });
+    }
 
     let onload = {
-        Callback::from(move |_| {
-            on_load_call.emit(());
+        let state = state.clone();
+        let on_load = props.on_load.clone();
+        Callback::from(move |event: Event| {
+            // A new `srcset` candidate loading once decoded is not a new load.
+            if current.next(ImageEvent::Load) == current {
+                return;
+            }
+            state.dispatch(ImageEvent::Load);
+            if let Some(img) = event.target_dyn_into::<HtmlImageElement>() {
+                on_load.emit(LoadEvent::from_element(&img, fallback_index));
+            }
         })
     };
 
-    let full_style = format!("{} {}", blur_style, img_style);
-
-    let layout = match props.layout {
-        Layout::Fill => {
-            html! {
-                <span style={"display: block; position: absolute; top: 0; left: 0; bottom: 0; right: 0;"}>
-                    <img
-                        src={props.src}
-                        alt={props.alt}
-                        width={props.width}
-                        height={props.height}
-                        style={full_style}
-                        class={props.class}
-                        loading={props.loading.as_str()}
-                        sizes={props.sizes}
-                        quality={props.quality}
-                        placeholder={props.placeholder}
-                        decoding={props.decoding.as_str()}
-                        ref={props.node_ref}
-                        role="img"
-                        aria-label={props.alt}
-                        aria-labelledby={props.aria_labelledby}
-                        aria-describedby={props.aria_describedby}
-                        aria-hidden={props.aria_hidden}
-                        aria-current={props.aria_current}
-                        aria-expanded={props.aria_expanded}
-                        aria-live={props.aria_live.as_str()}
-                        aria-pressed={props.aria_pressed.as_str()}
-                        aria-controls={props.aria_controls}
-                        onerror={fetch_data}
-                        crossorigin={props.crossorigin.as_str()}
-                        referrerpolicy={props.referrerpolicy.as_str()}
-                        fetchpriority={props.fetchpriority.as_str()}
-                        attributionsrc={props.attributionsrc}
-                        onload={onload}
-                        elementtiming={props.elementtiming}
-                        srcset={props.srcset}
-                        ismap={props.ismap}
-                        usemap={props.usemap}
-                    />
-                </span>
+    // The placeholder layer is removed once the image faded in.
+    let ontransitionend = {
+        let transitioned = transitioned.clone();
+        Callback::from(move |_: TransitionEvent| {
+            if current.is_settled() {
+                transitioned.set(true);
             }
-        }
-        Layout::Responsive => {
-            let quotient: f64 =
-                props.height.parse::<f64>().unwrap() / props.width.parse::<f64>().unwrap();
-            let padding_top: String = if quotient.is_nan() {
-                "100%".to_string()
-            } else {
-                format!("{}%", quotient * 100.0)
+        })
+    };
+    let placeholder = attrs
+        .placeholder_style(current, *transitioned)
+        .map(|style| {
+            let custom = match &props.placeholder {
+                Placeholder::Custom(view) => view.clone(),
+                _ => Html::default(),
             };
+            html! { <span style={style} aria-hidden="true">{custom}</span> }
+        });
 
-            html! {
-                <span style={"display: block; position: relative;"}>
-                    <span style={"padding-top: ".to_owned() + &padding_top}>
-                        <img
-                            src={props.src}
-                            alt={props.alt}
-                            width={props.width}
-                            height={props.height}
-                            style={full_style}
-                            class={props.class}
-                            sizes={props.sizes}
-                            quality={props.quality}
-                            loading={props.loading.as_str()}
-                            placeholder={props.placeholder}
-                            decoding={props.decoding.as_str()}
-                            ref={props.node_ref}
-                            role="img"
-                            aria-label={props.alt}
-                            aria-labelledby={props.aria_labelledby}
-                            aria-describedby={props.aria_describedby}
-                            aria-hidden={props.aria_hidden}
-                            aria-current={props.aria_current}
-                            aria-expanded={props.aria_expanded}
-                            aria-live={props.aria_live.as_str()}
-                            aria-pressed={props.aria_pressed.as_str()}
-                            aria-controls={props.aria_controls}
-                            onerror={fetch_data}
-                            crossorigin={props.crossorigin.as_str()}
-                            referrerpolicy={props.referrerpolicy.as_str()}
-                            fetchpriority={props.fetchpriority.as_str()}
-                            attributionsrc={props.attributionsrc}
-                            onload={onload}
-                            elementtiming={props.elementtiming}
-                            srcset={props.srcset}
-                            ismap={props.ismap}
-                            usemap={props.usemap}
-                        />
-                    </span>
-                </span>
-            }
-        }
-        Layout::Intrinsic => {
-            html! {
-                <span style={"display: inline-block; position: relative; max-width: 100%;"}>
-                    <span style={"max-width: 100%;"}>
-                        <img
-                            src={props.src}
-                            alt={props.alt}
-                            width={props.width}
-                            height={props.height}
-                            style={full_style}
-                            class={props.class}
-                            sizes={props.sizes}
-                            loading={props.loading.as_str()}
-                            quality={props.quality}
-                            placeholder={props.placeholder}
-                            decoding={props.decoding.as_str()}
-                            ref={props.node_ref}
-                            role="img"
-                            aria-label={props.alt}
-                            aria-labelledby={props.aria_labelledby}
-                            aria-describedby={props.aria_describedby}
-                            aria-hidden={props.aria_hidden}
-                            aria-current={props.aria_current}
-                            aria-expanded={props.aria_expanded}
-                            aria-live={props.aria_live.as_str()}
-                            aria-pressed={props.aria_pressed.as_str()}
-                            aria-controls={props.aria_controls}
-                            onerror={fetch_data}
-                            crossorigin={props.crossorigin.as_str()}
-                            referrerpolicy={props.referrerpolicy.as_str()}
-                            fetchpriority={props.fetchpriority.as_str()}
-                            attributionsrc={props.attributionsrc}
-                            onload={onload}
-                            elementtiming={props.elementtiming}
-                            srcset={props.srcset}
-                            ismap={props.ismap}
-                            usemap={props.usemap}
-                        />
-                    </span>
-                    <img
-                        src={props.blur_data_url}
-                        style={"display: none;"}
-                        alt={props.alt}
-                        aria-hidden="true"
-                    />
-                </span>
-            }
-        }
-        Layout::Fixed => {
-            html! {
-                <span style={"display: inline-block; position: relative;"}>
-                    <img
-                        src={props.src}
-                        alt={props.alt}
-                        width={props.width}
-                        height={props.height}
-                        style={full_style}
-                        class={props.class}
-                        sizes={props.sizes}
-                        quality={props.quality}
-                        loading={props.loading.as_str()}
-                        placeholder={props.placeholder}
-                        decoding={props.decoding.as_str()}
-                        ref={props.node_ref}
-                        role="img"
-                        aria-label={props.alt}
-                        aria-labelledby={props.aria_labelledby}
-                        aria-describedby={props.aria_describedby}
-                        aria-hidden={props.aria_hidden}
-                        aria-current={props.aria_current}
-                        aria-expanded={props.aria_expanded}
-                        aria-live={props.aria_live.as_str()}
-                        aria-pressed={props.aria_pressed.as_str()}
-                        aria-controls={props.aria_controls}
-                        onerror={fetch_data}
-                        crossorigin={props.crossorigin.as_str()}
-                        referrerpolicy={props.referrerpolicy.as_str()}
-                        fetchpriority={props.fetchpriority.as_str()}
-                        attributionsrc={props.attributionsrc}
-                        onload={onload}
-                        elementtiming={props.elementtiming}
-                        srcset={props.srcset}
-                        ismap={props.ismap}
-                        usemap={props.usemap}
-                    />
-                </span>
-            }
-        }
-        Layout::Auto => {
-            // Preserve the natural size of the image
-            html! {
-                <span style={"display: inline-block; position: relative;"}>
-                    <img
-                        src={props.src}
-                        alt={props.alt}
-                        width={props.width}
-                        height={props.height}
-                        style={full_style}
-                        class={props.class}
-                        sizes={props.sizes}
-                        quality={props.quality}
-                        placeholder={props.placeholder}
-                        loading={props.loading.as_str()}
-                        decoding={props.decoding.as_str()}
-                        ref={props.node_ref}
-                        role="img"
-                        aria-label={props.alt}
-                        aria-labelledby={props.aria_labelledby}
-                        aria-describedby={props.aria_describedby}
-                        aria-hidden={props.aria_hidden}
-                        aria-current={props.aria_current}
-                        aria-expanded={props.aria_expanded}
-                        aria-live={props.aria_live.as_str()}
-                        aria-pressed={props.aria_pressed.as_str()}
-                        aria-controls={props.aria_controls}
-                        onerror={fetch_data}
-                        crossorigin={props.crossorigin.as_str()}
-                        referrerpolicy={props.referrerpolicy.as_str()}
-                        fetchpriority={props.fetchpriority.as_str()}
-                        attributionsrc={props.attributionsrc}
-                        onload={onload}
-                        elementtiming={props.elementtiming}
-                        srcset={props.srcset}
-                        ismap={props.ismap}
-                        usemap={props.usemap}
-                    />
-                </span>
-            }
-        }
-        Layout::Stretch => {
-            // Make the image fill the container
-            html! {
-                <span style={"display: block; width: 100%; height: 100%; position: relative;"}>
-                    <img
-                        src={props.src}
-                        alt={props.alt}
-                        width="100%"
-                        height="100%"
-                        style={full_style}
-                        class={props.class}
-                        loading={props.loading.as_str()}
-                        sizes={props.sizes}
-                        quality={props.quality}
-                        placeholder={props.placeholder}
-                        decoding={props.decoding.as_str()}
-                        ref={props.node_ref}
-                        role="img"
-                        aria-label={props.alt}
-                        aria-labelledby={props.aria_labelledby}
-                        aria-describedby={props.aria_describedby}
-                        aria-hidden={props.aria_hidden}
-                        aria-current={props.aria_current}
-                        aria-expanded={props.aria_expanded}
-                        aria-live={props.aria_live.as_str()}
-                        aria-pressed={props.aria_pressed.as_str()}
-                        aria-controls={props.aria_controls}
-                        onerror={fetch_data}
-                        crossorigin={props.crossorigin.as_str()}
-                        referrerpolicy={props.referrerpolicy.as_str()}
-                        fetchpriority={props.fetchpriority.as_str()}
-                        attributionsrc={props.attributionsrc}
-                        onload={onload}
-                        elementtiming={props.elementtiming}
-                        srcset={props.srcset}
-                        ismap={props.ismap}
-                        usemap={props.usemap}
-                    />
-                </span>
-            }
-        }
-        Layout::ScaleDown => {
-            // Maintain aspect ratio, but scale down if image is too large
-            html! {
-                <span style={"display: inline-block; position: relative; max-width: 100%; max-height: 100%;"}>
-                    <img
-                        src={props.src}
-                        alt={props.alt}
-                        width={props.width}
-                        height={props.height}
-                        style={full_style}
-                        class={props.class}
-                        loading={props.loading.as_str()}
-                        sizes={props.sizes}
-                        quality={props.quality}
-                        placeholder={props.placeholder}
-                        decoding={props.decoding.as_str()}
-                        ref={props.node_ref}
-                        role="img"
-                        aria-label={props.alt}
-                        aria-labelledby={props.aria_labelledby}
-                        aria-describedby={props.aria_describedby}
-                        aria-hidden={props.aria_hidden}
-                        aria-current={props.aria_current}
-                        aria-expanded={props.aria_expanded}
-                        aria-live={props.aria_live.as_str()}
-                        aria-pressed={props.aria_pressed.as_str()}
-                        aria-controls={props.aria_controls}
-                        onerror={fetch_data}
-                        crossorigin={props.crossorigin.as_str()}
-                        referrerpolicy={props.referrerpolicy.as_str()}
-                        fetchpriority={props.fetchpriority.as_str()}
-                        attributionsrc={props.attributionsrc}
-                        onload={onload}
-                        elementtiming={props.elementtiming}
-                        srcset={props.srcset}
-                        ismap={props.ismap}
-                        usemap={props.usemap}
+    let img = html! {
+        <img
+            src={attrs.current_src(current, fallback_src)}
+            alt={props.alt.clone()}
+            width={attrs.width.clone()}
+            height={attrs.height.clone()}
+            style={attrs.current_style(current)}
+            class={props.class.clone()}
+            loading={attrs.loading.as_str()}
+            sizes={attrs.sizes.clone()}
+            decoding={attrs.decoding.as_str()}
+            ref={props.node_ref.clone()}
+            role="img"
+            aria-label={props.alt.clone()}
+            aria-labelledby={props.aria_labelledby.clone()}
+            aria-describedby={props.aria_describedby.clone()}
+            aria-hidden={props.aria_hidden.clone()}
+            aria-current={props.aria_current.clone()}
+            aria-expanded={props.aria_expanded.clone()}
+            aria-live={props.aria_live.as_str()}
+            aria-pressed={props.aria_pressed.as_str()}
+            aria-controls={props.aria_controls.clone()}
+            onerror={onerror}
+            crossorigin={props.crossorigin.as_str()}
+            referrerpolicy={props.referrerpolicy.as_str()}
+            fetchpriority={attrs.fetchpriority.as_str()}
+            attributionsrc={props.attributionsrc.clone()}
+            onload={onload}
+            elementtiming={props.elementtiming.clone()}
+            srcset={attrs.current_srcset(current, fallback_src)}
+            ismap={props.ismap}
+            usemap={props.usemap.clone()}
+            ontransitionend={ontransitionend}
+        />
+    };
+    // The `<picture>` stays in place while the sources come and go, so the `<img>` is kept.
+    let img = if attrs.sources.is_empty() {
+        img
+    } else {
+        let sources = attrs
+            .current_sources(current, fallback_src)
+            .iter()
+            .map(|source| {
+                html! {
+                    <source
+                        media={non_empty(&source.media)}
+                        type={non_empty(&source.mime_type)}
+                        srcset={source.srcset.clone()}
+                        sizes={non_empty(&source.sizes)}
                     />
-                </span>
-            }
-        }
+                }
+            });
+        html! { <picture>{for sources}{img}</picture> }
+    };
+    let img = html! {
+        <>
+            {for placeholder}
+            {img}
+        </>
+    };
+
+    let img = match attrs.wrapper_style {
+        Some(wrapper_style) => html! { <span style={wrapper_style}>{img}</span> },
+        None => img,
     };
+
     html! {
-            {layout}
+        <span style={attrs.container_style}>
+            if let Some(spacer_style) = attrs.spacer_style.clone() {
+                <span style={spacer_style} />
+            }
+            {img}
+        </span>
+    }
+}
+
+/// Loads `src` outside of an `<img>` and returns its load status.
+///
+/// The image goes through the same loader, state machine and fallback chain as [`Image`], so
+/// the status can drive a spinner or a button while the image is rendered elsewhere, or not at
+/// all. The component re-renders on every change of status, and a change of `src` or
+/// `options` starts over.
+///
+/// # Example
+/// ```rust
+/// use yew::prelude::*;
+/// use image_rs::yew::use_image;
+/// use image_rs::UseImageOptions;
+///
+/// #[function_component(Download)]
+/// pub fn download() -> Html {
+///     let status = use_image("/images/photo.jpg".into(), UseImageOptions::default());
+///     html! {
+///         <button disabled={!status.is_loaded()}>{"Download"}</button>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_image(src: AttrValue, options: UseImageOptions) -> ImageStatus {
+    let status = use_state(ImageStatus::default);
+    {
+        let status = status.clone();
+        use_effect_with((src, options), move |(src, options)| {
+            let cancelled = Rc::new(Cell::new(false));
+            {
+                let cancelled = cancelled.clone();
+                let (src, options) = (src.clone(), options.clone());
+                spawn_local(async move {
+                    let is_cancelled = || cancelled.get();
+                    load_image(&src, &options, is_cancelled, |next| status.set(next)).await;
+                });
+            }
+            move || cancelled.set(true)
+        });
     }
+    (*status).clone()
 }